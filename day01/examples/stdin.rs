@@ -0,0 +1,12 @@
+use std::io;
+
+use day1::locations::LocationLists;
+
+fn main() -> io::Result<()> {
+    let lists = LocationLists::read(io::stdin().lock())?;
+
+    println!("Total distance: {}", lists.total_distance());
+    println!("Similarity score: {}", lists.similarity_score());
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod locations;
+pub mod solution;
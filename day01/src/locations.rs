@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead};
+
+use utils::tuple_split_whitespace_parse;
+
+pub type LocationId = i64;
+
+#[derive(Debug, Default, Clone)]
+pub struct LocationLists {
+    left: Vec<LocationId>,
+    right: Vec<LocationId>,
+}
+
+impl LocationLists {
+    pub fn parse(input: &str) -> Self {
+        let mut lists = Self::default();
+
+        for line in input.lines() {
+            lists.push_line(line);
+        }
+
+        lists
+    }
+
+    pub fn read(reader: impl BufRead) -> io::Result<Self> {
+        let mut lists = Self::default();
+
+        for line in reader.lines() {
+            lists.push_line(&line?);
+        }
+
+        Ok(lists)
+    }
+
+    pub fn push_line(&mut self, line: &str) {
+        if line.trim().is_empty() {
+            return;
+        }
+
+        let (a, b) = tuple_split_whitespace_parse(line);
+
+        self.left.push(a);
+        self.right.push(b);
+    }
+
+    pub fn total_distance(&self) -> LocationId {
+        let mut left = self.left.clone();
+        let mut right = self.right.clone();
+
+        left.sort_unstable();
+        right.sort_unstable();
+
+        left.into_iter()
+            .zip(right)
+            .map(|(a, b)| (a - b).abs())
+            .sum()
+    }
+
+    pub fn similarity_score(&self) -> LocationId {
+        let mut occurrences = HashMap::<LocationId, LocationId>::new();
+
+        for &b in &self.right {
+            *occurrences.entry(b).or_default() += 1;
+        }
+
+        self.left
+            .iter()
+            .map(|a| a * occurrences.get(a).copied().unwrap_or_default())
+            .sum()
+    }
+}
@@ -0,0 +1,26 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::locations::LocationLists;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = LocationLists;
+
+    fn shape() -> Shape {
+        Shape::lines(r"\d+\s+\d+")
+    }
+
+    fn parse(input: &str) -> LocationLists {
+        LocationLists::parse(input)
+    }
+
+    fn part1(lists: &LocationLists) -> Answer {
+        lists.total_distance().into()
+    }
+
+    fn part2(lists: &LocationLists) -> Answer {
+        lists.similarity_score().into()
+    }
+}
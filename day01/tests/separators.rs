@@ -0,0 +1,13 @@
+use day1::solution::Day1;
+use utils::solution::Solution;
+
+const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+// The location ids may be separated by any whitespace, not only spaces
+#[test]
+fn spaces_and_tabs() {
+    for input in [EXAMPLE.to_string(), EXAMPLE.replace("   ", "\t")] {
+        assert_eq!(Day1::solve(&input, 1).to_string(), "11");
+        assert_eq!(Day1::solve(&input, 2).to_string(), "31");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod reports;
+pub mod solution;
@@ -0,0 +1,155 @@
+use std::ops::RangeInclusive;
+
+use utils::StrExt;
+
+pub type Level = i32;
+
+pub fn parse_reports(input: &str) -> Vec<Vec<Level>> {
+    input
+        .lines()
+        .map(|line| line.split_whitespace().map(|level| level.i32()).collect())
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Gradient {
+    Ascending,
+    Descending,
+}
+
+impl Gradient {
+    pub fn between(a: Level, b: Level, step_bounds: &RangeInclusive<Level>) -> Option<Self> {
+        if !step_bounds.contains(&(a - b).abs()) {
+            return None;
+        }
+
+        if a < b {
+            Some(Gradient::Ascending)
+        } else {
+            Some(Gradient::Descending)
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Safety {
+    Safe,
+    SafeWithRemovals(Vec<usize>),
+    Unsafe,
+}
+
+impl Safety {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Safety::Unsafe)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Analyzer {
+    pub step_bounds: RangeInclusive<Level>,
+    pub max_removals: usize,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self {
+            step_bounds: 1..=3,
+            max_removals: 0,
+        }
+    }
+}
+
+impl Analyzer {
+    pub fn with_max_removals(max_removals: usize) -> Self {
+        Self {
+            max_removals,
+            ..Self::default()
+        }
+    }
+
+    pub fn analyze(&self, levels: &[Level]) -> Safety {
+        let removals = [Gradient::Ascending, Gradient::Descending]
+            .into_iter()
+            .filter_map(|gradient| self.min_removals(levels, gradient))
+            .min_by_key(|removals| removals.len());
+
+        match removals {
+            Some(removals) if removals.is_empty() => Safety::Safe,
+            Some(removals) => Safety::SafeWithRemovals(removals),
+            None => Safety::Unsafe,
+        }
+    }
+
+    // Finds the fewest levels to remove so that the remaining levels follow `gradient`.
+    // `previous[i][r]` records how level `i` can be the latest kept level after `r` removals,
+    // which only ever looks back `max_removals + 1` levels and is therefore linear in the report length.
+    fn min_removals(&self, levels: &[Level], gradient: Gradient) -> Option<Vec<usize>> {
+        if levels.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let max_removals = self.max_removals;
+        let mut previous = vec![vec![None::<Option<usize>>; max_removals + 1]; levels.len()];
+
+        for i in 0..levels.len() {
+            if i <= max_removals {
+                previous[i][i] = Some(None);
+            }
+
+            for j in i.saturating_sub(max_removals + 1)..i {
+                let skipped = i - j - 1;
+
+                if Gradient::between(levels[j], levels[i], &self.step_bounds) != Some(gradient) {
+                    continue;
+                }
+
+                for removals in 0..=max_removals - skipped {
+                    if previous[j][removals].is_some() && previous[i][removals + skipped].is_none()
+                    {
+                        previous[i][removals + skipped] = Some(Some(j));
+                    }
+                }
+            }
+        }
+
+        let mut best_end = None;
+
+        for (i, previous) in previous.iter().enumerate() {
+            let trailing = levels.len() - 1 - i;
+
+            for (removals, previous) in previous.iter().enumerate() {
+                let total_removals = removals + trailing;
+
+                if previous.is_none() || total_removals > max_removals {
+                    continue;
+                }
+
+                if best_end.is_none_or(|(_, _, best)| total_removals < best) {
+                    best_end = Some((i, removals, total_removals));
+                }
+            }
+        }
+
+        let (mut last, mut removals, _) = best_end?;
+        let mut kept = vec![false; levels.len()];
+
+        loop {
+            kept[last] = true;
+
+            let Some(Some(j)) = previous[last][removals] else {
+                break;
+            };
+
+            removals -= last - j - 1;
+            last = j;
+        }
+
+        Some(
+            kept.iter()
+                .enumerate()
+                .filter(|(_, kept)| !**kept)
+                .map(|(index, _)| index)
+                .collect(),
+        )
+    }
+}
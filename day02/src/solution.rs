@@ -0,0 +1,33 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::reports::{parse_reports, Analyzer, Level};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Vec<Level>>;
+
+    fn shape() -> Shape {
+        Shape::numbers(" ")
+    }
+
+    fn parse(input: &str) -> Vec<Vec<Level>> {
+        parse_reports(input)
+    }
+
+    fn part1(reports: &Vec<Vec<Level>>) -> Answer {
+        count_safe(reports, &Analyzer::default()).into()
+    }
+
+    fn part2(reports: &Vec<Vec<Level>>) -> Answer {
+        count_safe(reports, &Analyzer::with_max_removals(1)).into()
+    }
+}
+
+fn count_safe(reports: &[Vec<Level>], analyzer: &Analyzer) -> usize {
+    reports
+        .iter()
+        .filter(|levels| analyzer.analyze(levels).is_safe())
+        .count()
+}
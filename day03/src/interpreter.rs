@@ -0,0 +1,146 @@
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Op {
+    Mul,
+    Enable,
+    Disable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionSpec {
+    pub name: &'static str,
+    pub op: Op,
+    pub arity: usize,
+    pub digits: RangeInclusive<usize>,
+}
+
+impl InstructionSpec {
+    pub fn new(name: &'static str, op: Op, arity: usize) -> Self {
+        Self {
+            name,
+            op,
+            arity,
+            digits: 1..=3,
+        }
+    }
+
+    fn parse<'a>(&self, memory: &'a [u8]) -> Option<(Vec<i64>, &'a [u8])> {
+        let mut memory = memory.strip_prefix(self.name.as_bytes())?;
+        let mut args = Vec::with_capacity(self.arity);
+
+        memory = memory.strip_prefix(b"(")?;
+
+        for i in 0..self.arity {
+            if i > 0 {
+                memory = memory.strip_prefix(b",")?;
+            }
+
+            let (arg, rest) = self.parse_arg(memory)?;
+
+            args.push(arg);
+            memory = rest;
+        }
+
+        memory = memory.strip_prefix(b")")?;
+
+        Some((args, memory))
+    }
+
+    fn parse_arg<'a>(&self, memory: &'a [u8]) -> Option<(i64, &'a [u8])> {
+        let num_digits = memory
+            .iter()
+            .take(self.digits.end() + 1)
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        if !self.digits.contains(&num_digits) {
+            return None;
+        }
+
+        let (digits, memory) = memory.split_at(num_digits);
+        let arg = digits
+            .iter()
+            .fold(0, |arg, digit| arg * 10 + i64::from(digit - b'0'));
+
+        Some((arg, memory))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InstructionTable {
+    specs: Vec<InstructionSpec>,
+}
+
+impl InstructionTable {
+    pub fn new(specs: Vec<InstructionSpec>) -> Self {
+        Self { specs }
+    }
+
+    pub fn puzzle() -> Self {
+        Self::new(vec![
+            InstructionSpec::new("mul", Op::Mul, 2),
+            InstructionSpec::new("do", Op::Enable, 0),
+            InstructionSpec::new("don't", Op::Disable, 0),
+        ])
+    }
+
+    pub fn tokenize(&self, memory: &str) -> Vec<Instruction> {
+        let mut memory = memory.as_bytes();
+        let mut offset = 0;
+        let mut instructions = Vec::new();
+
+        while !memory.is_empty() {
+            let parsed = self
+                .specs
+                .iter()
+                .find_map(|spec| Some((spec.op, spec.parse(memory)?)));
+
+            // Skipping a single byte keeps the scan linear, and is safe with
+            // multi-byte characters because instruction names are ASCII.
+            let Some((op, (args, rest))) = parsed else {
+                memory = &memory[1..];
+                offset += 1;
+                continue;
+            };
+
+            instructions.push(Instruction { op, args, offset });
+            offset += memory.len() - rest.len();
+            memory = rest;
+        }
+
+        instructions
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Op,
+    pub args: Vec<i64>,
+    pub offset: usize,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    IgnoreConditionals,
+    RespectConditionals,
+}
+
+pub fn execute(instructions: &[Instruction], mode: Mode) -> i64 {
+    let mut sum = 0;
+    let mut enabled = true;
+
+    for instruction in instructions {
+        match instruction.op {
+            Op::Mul => {
+                if enabled || mode == Mode::IgnoreConditionals {
+                    sum += instruction.args.iter().product::<i64>()
+                }
+            }
+            Op::Enable => enabled = true,
+            Op::Disable => enabled = false,
+        }
+    }
+
+    sum
+}
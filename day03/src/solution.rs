@@ -0,0 +1,21 @@
+use utils::solution::{Answer, Solution};
+
+use crate::interpreter::{execute, Instruction, InstructionTable, Mode};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Vec<Instruction> {
+        InstructionTable::puzzle().tokenize(input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Answer {
+        execute(instructions, Mode::IgnoreConditionals).into()
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Answer {
+        execute(instructions, Mode::RespectConditionals).into()
+    }
+}
@@ -0,0 +1,17 @@
+use day3::solution::Day3;
+use utils::fixture::Fixture;
+use utils::solution::Solution;
+
+// Examples live in `fixtures/`, written by `scaffold examples` or by hand
+#[test]
+fn examples() {
+    for fixture in Fixture::load_all(env!("CARGO_MANIFEST_DIR")) {
+        for part in [1, 2] {
+            if let Some(expected) = fixture.answer(part) {
+                let answer = Day3::solve(&fixture.input, part).to_string();
+
+                assert_eq!(answer, expected, "part {part} of {}", fixture.name);
+            }
+        }
+    }
+}
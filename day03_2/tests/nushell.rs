@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use day3::solution::Day3;
+use utils::solution::Solution;
+
+const EXAMPLE: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n";
+
+// The Nushell scripts read `input.txt` from the directory they run in
+fn run_nu(script: &str, input: &str) -> String {
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join(script);
+    let dir = env::temp_dir().join(format!("day03-nu-{}", std::process::id()));
+
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("input.txt"), input).unwrap();
+
+    let output = Command::new("nu")
+        .arg(&script)
+        .current_dir(&dir)
+        .output()
+        .expect("failed to run `nu`, is Nushell installed?");
+
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+#[test]
+#[ignore = "needs Nushell, run with `cargo test -p day3_2 -- --ignored`"]
+fn nushell_matches_interpreter() {
+    for (part, script) in [(1, "../day03/main.nu"), (2, "main.nu")] {
+        let output = run_nu(script, EXAMPLE);
+
+        assert_eq!(output, Day3::solve(EXAMPLE, part).to_string(), "{script}");
+    }
+}
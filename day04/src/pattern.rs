@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+pub type Grid = HashMap<Position, char>;
+pub type Position = (i32, i32);
+pub type Offset = (i32, i32);
+pub type Constraint = (Offset, char);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    constraints: Vec<Constraint>,
+}
+
+impl Pattern {
+    pub fn new(constraints: impl IntoIterator<Item = Constraint>) -> Self {
+        let mut constraints = constraints.into_iter().collect::<Vec<_>>();
+        let min_x = constraints.iter().map(|((x, _), _)| *x).min().unwrap_or(0);
+        let min_y = constraints.iter().map(|((_, y), _)| *y).min().unwrap_or(0);
+
+        // Anchor every pattern at the top left corner of its bounding box,
+        // so that transformed patterns can be compared for equality.
+        for ((x, y), _) in &mut constraints {
+            *x -= min_x;
+            *y -= min_y;
+        }
+
+        constraints.sort();
+        constraints.dedup();
+
+        Self { constraints }
+    }
+
+    pub fn word(word: &str, (xd, yd): Offset) -> Self {
+        Self::new(word.chars().zip(0..).map(|(ch, i)| ((i * xd, i * yd), ch)))
+    }
+
+    // Any character in `wildcards` matches every cell
+    pub fn stencil(stencil: &str, wildcards: &str) -> Self {
+        Self::new(stencil.lines().zip(0..).flat_map(|(line, y)| {
+            line.chars()
+                .zip(0..)
+                .filter(|(ch, _)| !wildcards.contains(*ch))
+                .map(move |(ch, x)| ((x, y), ch))
+        }))
+    }
+
+    pub fn constraints(&self) -> &[Constraint] {
+        &self.constraints
+    }
+
+    pub fn rotated_right(&self) -> Self {
+        Self::new(self.constraints.iter().map(|&((x, y), ch)| ((-y, x), ch)))
+    }
+
+    pub fn reflected(&self) -> Self {
+        Self::new(self.constraints.iter().map(|&((x, y), ch)| ((-x, y), ch)))
+    }
+
+    pub fn transformed(&self, orientation: Orientation) -> Self {
+        let mut pattern = if orientation.reflected {
+            self.reflected()
+        } else {
+            self.clone()
+        };
+
+        for _ in 0..orientation.quarter_turns {
+            pattern = pattern.rotated_right();
+        }
+
+        pattern
+    }
+
+    pub fn rotations(&self) -> Vec<OrientedPattern> {
+        self.distinct_orientations(&[false])
+    }
+
+    pub fn orientations(&self) -> Vec<OrientedPattern> {
+        self.distinct_orientations(&[false, true])
+    }
+
+    fn distinct_orientations(&self, reflections: &[bool]) -> Vec<OrientedPattern> {
+        let mut oriented_patterns = Vec::<OrientedPattern>::new();
+
+        for &reflected in reflections {
+            for quarter_turns in 0..4 {
+                let orientation = Orientation {
+                    quarter_turns,
+                    reflected,
+                };
+                let pattern = self.transformed(orientation);
+
+                if oriented_patterns
+                    .iter()
+                    .any(|oriented_pattern| oriented_pattern.pattern == pattern)
+                {
+                    continue;
+                }
+
+                oriented_patterns.push(OrientedPattern {
+                    pattern,
+                    orientation,
+                });
+            }
+        }
+
+        oriented_patterns
+    }
+
+    pub fn matches_at(&self, grid: &Grid, (x, y): Position) -> bool {
+        self.constraints
+            .iter()
+            .all(|&((xd, yd), ch)| grid.get(&(x + xd, y + yd)) == Some(&ch))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub quarter_turns: u8,
+    pub reflected: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrientedPattern {
+    pub pattern: Pattern,
+    pub orientation: Orientation,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub position: Position,
+    pub orientation: Orientation,
+}
+
+pub fn find_matches<'a>(
+    grid: &'a Grid,
+    patterns: &'a [OrientedPattern],
+) -> impl Iterator<Item = Match> + 'a {
+    grid.keys().flat_map(move |&position| {
+        patterns
+            .iter()
+            .filter(move |oriented_pattern| oriented_pattern.pattern.matches_at(grid, position))
+            .map(move |oriented_pattern| Match {
+                position,
+                orientation: oriented_pattern.orientation,
+            })
+    })
+}
+
+pub fn parse_grid(input: &str) -> Grid {
+    let mut grid = HashMap::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, ch) in line.chars().enumerate() {
+            let x = x as i32;
+            let y = y as i32;
+
+            grid.insert((x, y), ch);
+        }
+    }
+
+    grid
+}
@@ -0,0 +1,38 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::pattern::{find_matches, parse_grid, Grid, Pattern};
+
+const X_MAS: &str = "\
+M.S
+.A.
+M.S";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Grid;
+
+    fn shape() -> Shape {
+        Shape::grid("XMAS")
+    }
+
+    fn parse(input: &str) -> Grid {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Grid) -> Answer {
+        let patterns = [(1, 0), (1, 1)]
+            .into_iter()
+            .flat_map(|direction| Pattern::word("XMAS", direction).rotations())
+            .collect::<Vec<_>>();
+
+        find_matches(grid, &patterns).count().into()
+    }
+
+    fn part2(grid: &Grid) -> Answer {
+        let patterns = Pattern::stencil(X_MAS, ".").rotations();
+
+        find_matches(grid, &patterns).count().into()
+    }
+}
@@ -0,0 +1,19 @@
+use utils::parse::{int, lines, separated, Parser};
+use utils::StrExt;
+
+pub mod precedence;
+pub mod solution;
+use precedence::{Rule, Update};
+
+pub fn parse_input(input: &str) -> (Vec<Rule>, Vec<Update>) {
+    let rule = (int(), '|', int()).map(|(before, _, after)| (before, after));
+    let update = separated(int(), ',');
+
+    input.parse_with(
+        (lines(rule), "\n\n", lines(update)).map(|(rules, _, updates)| (rules, updates)),
+    )
+}
+
+pub fn middle_page_number(update: &[i32]) -> i32 {
+    update[update.len() / 2]
+}
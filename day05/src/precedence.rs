@@ -0,0 +1,174 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+
+pub type Page = i32;
+pub type Rule = (Page, Page);
+pub type Update = Vec<Page>;
+
+#[derive(Debug, Default)]
+pub struct Precedence {
+    successors: HashMap<Page, HashSet<Page>>,
+}
+
+impl Precedence {
+    pub fn new(rules: &[Rule]) -> Self {
+        let mut precedence = Self::default();
+
+        for &(before, after) in rules {
+            precedence
+                .successors
+                .entry(before)
+                .or_default()
+                .insert(after);
+        }
+
+        precedence
+    }
+
+    pub fn must_precede(&self, before: Page, after: Page) -> bool {
+        self.successors
+            .get(&before)
+            .is_some_and(|successors| successors.contains(&after))
+    }
+
+    pub fn update_is_valid(&self, update: &[Page]) -> bool {
+        let mut seen = HashSet::new();
+
+        for &page in update {
+            if let Some(successors) = self.successors.get(&page) {
+                if !successors.is_disjoint(&seen) {
+                    return false;
+                }
+            }
+
+            seen.insert(page);
+        }
+
+        true
+    }
+
+    pub fn fix_update_page_order(&self, update: &[Page]) -> Result<Update, RuleCycle> {
+        let mut successors = vec![Vec::new(); update.len()];
+        let mut in_degrees = vec![0; update.len()];
+
+        for (a, &page_a) in update.iter().enumerate() {
+            for (b, &page_b) in update.iter().enumerate() {
+                if self.must_precede(page_a, page_b) {
+                    successors[a].push(b);
+                    in_degrees[b] += 1;
+                }
+            }
+        }
+
+        // Pages without constraints between them keep their original relative order
+        let mut ready = in_degrees
+            .iter()
+            .enumerate()
+            .filter(|(_, in_degree)| **in_degree == 0)
+            .map(|(index, _)| Reverse(index))
+            .collect::<BinaryHeap<_>>();
+        let mut fixed_update = Update::with_capacity(update.len());
+
+        while let Some(Reverse(index)) = ready.pop() {
+            fixed_update.push(update[index]);
+
+            for &successor in &successors[index] {
+                in_degrees[successor] -= 1;
+
+                if in_degrees[successor] == 0 {
+                    ready.push(Reverse(successor));
+                }
+            }
+        }
+
+        if fixed_update.len() < update.len() {
+            // Pages left over are on a cycle or only downstream of one
+            let pages = (0..update.len())
+                .filter(|&index| in_degrees[index] > 0 && reaches(&successors, index, index))
+                .map(|index| update[index])
+                .collect();
+
+            return Err(RuleCycle { pages });
+        }
+
+        Ok(fixed_update)
+    }
+}
+
+// Whether `to` can be reached from `from` in one or more steps
+fn reaches(successors: &[Vec<usize>], from: usize, to: usize) -> bool {
+    let mut visited = vec![false; successors.len()];
+    let mut stack = successors[from].clone();
+
+    while let Some(index) = stack.pop() {
+        if index == to {
+            return true;
+        }
+
+        if !std::mem::replace(&mut visited[index], true) {
+            stack.extend(&successors[index]);
+        }
+    }
+
+    false
+}
+
+#[derive(Debug)]
+pub struct RuleCycle {
+    pub pages: Vec<Page>,
+}
+
+impl fmt::Display for RuleCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pages = self
+            .pages
+            .iter()
+            .map(|page| page.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        write!(
+            f,
+            "page ordering rules contain a cycle involving pages {pages}"
+        )
+    }
+}
+
+impl std::error::Error for RuleCycle {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixes_the_order_of_pages() {
+        let precedence = Precedence::new(&[(1, 2), (2, 3), (1, 3)]);
+
+        assert_eq!(
+            precedence.fix_update_page_order(&[3, 2, 1]).unwrap(),
+            [1, 2, 3]
+        );
+
+        // Unconstrained pages keep their relative order
+        assert_eq!(
+            precedence.fix_update_page_order(&[9, 3, 8, 1]).unwrap(),
+            [9, 8, 1, 3]
+        );
+    }
+
+    #[test]
+    fn reports_only_pages_on_a_cycle() {
+        // 1 → 2 → 3 → 2 → …, with 4 and 5 only downstream of the cycle
+        let precedence = Precedence::new(&[(1, 2), (2, 3), (3, 2), (3, 4), (4, 5)]);
+        let cycle = precedence
+            .fix_update_page_order(&[5, 4, 3, 2, 1])
+            .unwrap_err();
+
+        assert_eq!(cycle.pages, [3, 2]);
+        assert_eq!(
+            cycle.to_string(),
+            "page ordering rules contain a cycle involving pages 3, 2"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::precedence::{Precedence, Update};
+use crate::{middle_page_number, parse_input};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (Precedence, Vec<Update>);
+
+    fn shape() -> Shape {
+        Shape::sections([Shape::lines(r"\d+\|\d+"), Shape::numbers(",")])
+    }
+
+    fn parse(input: &str) -> (Precedence, Vec<Update>) {
+        let (rules, updates) = parse_input(input);
+
+        (Precedence::new(&rules), updates)
+    }
+
+    fn part1((precedence, updates): &(Precedence, Vec<Update>)) -> Answer {
+        updates
+            .iter()
+            .filter(|update| precedence.update_is_valid(update))
+            .map(|update| middle_page_number(update))
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2((precedence, updates): &(Precedence, Vec<Update>)) -> Answer {
+        updates
+            .iter()
+            .filter(|update| !precedence.update_is_valid(update))
+            .map(|update| {
+                precedence
+                    .fix_update_page_order(update)
+                    .unwrap_or_else(|cycle| panic!("{cycle}"))
+            })
+            .map(|update| middle_page_number(&update))
+            .sum::<i32>()
+            .into()
+    }
+}
@@ -0,0 +1,102 @@
+use std::iter;
+
+use fnv::FnvHashMap;
+use itertools::Itertools;
+use utils::bits::BitGrid3;
+
+use crate::direction::Direction;
+use crate::position::Position;
+
+type Grid = FnvHashMap<Position, char>;
+
+pub struct Lab {
+    width: usize,
+    height: usize,
+    grid: Grid,
+}
+
+impl Lab {
+    pub fn parse(input: &str) -> Self {
+        let mut grid = FnvHashMap::default();
+        let width = input.lines().next().map_or(0, str::len);
+        let height = input.lines().count();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                let position = Position(x as i32, y as i32);
+
+                grid.insert(position, ch);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            grid,
+        }
+    }
+
+    // Distinct positions the guard passes before leaving the lab
+    pub fn visited(&self) -> usize {
+        guard_path(&self.grid)
+            .map(|(position, _)| position)
+            .unique()
+            .count()
+    }
+
+    pub fn looping_blockades(&self) -> impl Iterator<Item = Position> + '_ {
+        guard_path(&self.grid)
+            .map(|(position, _)| position)
+            .filter(|position| {
+                if self.grid.get(position) != Some(&'.') {
+                    return false;
+                }
+
+                let mut grid = self.grid.clone();
+
+                grid.insert(*position, '#');
+
+                self.is_loop(&grid)
+            })
+            .unique()
+    }
+
+    // The guard walks in a loop once they are at the same position facing the same way again
+    fn is_loop(&self, grid: &Grid) -> bool {
+        let mut seen = BitGrid3::new(self.width, self.height, 4);
+
+        guard_path(grid)
+            .any(|(position, direction)| !seen.insert(position.coords(), direction as usize))
+    }
+}
+
+fn guard_path(grid: &Grid) -> impl Iterator<Item = (Position, Direction)> + '_ {
+    let mut position = find_start_position(grid);
+    let mut direction = Direction::Up;
+
+    iter::from_fn(move || {
+        if !grid.contains_key(&position) {
+            return None;
+        }
+
+        let result = Some((position, direction));
+        let next_position = position + direction;
+
+        if grid.get(&next_position) == Some(&'#') {
+            direction.rotate_right();
+            return result;
+        }
+
+        position = next_position;
+
+        result
+    })
+}
+
+fn find_start_position(grid: &Grid) -> Position {
+    grid.iter()
+        .find(|(_, cell)| **cell == '^')
+        .map(|(position, _)| position)
+        .copied()
+        .unwrap()
+}
@@ -0,0 +1,4 @@
+pub mod direction;
+pub mod lab;
+pub mod position;
+pub mod solution;
@@ -1,6 +1,6 @@
 use std::ops::{Add, AddAssign};
 
-use crate::Direction;
+use crate::direction::Direction;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Position {
@@ -13,6 +13,12 @@ pub fn Position(x: i32, y: i32) -> Position {
     Position { x, y }
 }
 
+impl Position {
+    pub fn coords(self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+}
+
 impl Add<Direction> for Position {
     type Output = Position;
 
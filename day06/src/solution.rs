@@ -0,0 +1,26 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::lab::Lab;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Lab;
+
+    fn shape() -> Shape {
+        Shape::grid(".#^")
+    }
+
+    fn parse(input: &str) -> Lab {
+        Lab::parse(input)
+    }
+
+    fn part1(lab: &Lab) -> Answer {
+        lab.visited().into()
+    }
+
+    fn part2(lab: &Lab) -> Answer {
+        lab.looping_blockades().count().into()
+    }
+}
@@ -0,0 +1,70 @@
+use std::iter;
+
+use itertools::Itertools;
+use utils::{tuple_split, StrExt};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operator {
+    fn apply(self, left: i64, right: i64) -> i64 {
+        match self {
+            Operator::Add => left + right,
+            Operator::Multiply => left * right,
+            Operator::Concat => format!("{left}{right}").i64(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    pub test_value: i64,
+    values: Vec<i64>,
+}
+
+impl Equation {
+    pub fn parse(equation: &str) -> Self {
+        let (test_value, values) = tuple_split(equation, ": ");
+        let test_value = test_value.i64();
+        let values = values.split(" ").map(<_>::i64).collect();
+
+        Self { test_value, values }
+    }
+
+    pub fn can_be_true(&self, operators: &[Operator]) -> bool {
+        for operations in possible_operations(operators, self.values.len() - 1) {
+            let mut values = self.values.iter().copied();
+            let first = values.next().unwrap();
+            let result = values
+                .zip(operations)
+                .fold(first, |result, (value, op)| op.apply(result, value));
+
+            if result == self.test_value {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+pub fn parse_equations(equations: &str) -> Vec<Equation> {
+    equations.lines().map(Equation::parse).collect()
+}
+
+// Sum of the test values of the equations that can be made true
+pub fn calibration_result(equations: &[Equation], operators: &[Operator]) -> i64 {
+    equations
+        .iter()
+        .filter(|equation| equation.can_be_true(operators))
+        .map(|equation| equation.test_value)
+        .sum()
+}
+
+fn possible_operations(operators: &[Operator], len: usize) -> impl Iterator<Item = Vec<Operator>> {
+    iter::repeat_n(operators.to_vec(), len).multi_cartesian_product()
+}
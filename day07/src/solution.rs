@@ -0,0 +1,28 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::equations::{calibration_result, parse_equations, Equation, Operator};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<Equation>;
+
+    fn shape() -> Shape {
+        Shape::lines(r"\d+:( \d+)+")
+    }
+
+    fn parse(input: &str) -> Vec<Equation> {
+        parse_equations(input)
+    }
+
+    fn part1(equations: &Vec<Equation>) -> Answer {
+        calibration_result(equations, &[Operator::Add, Operator::Multiply]).into()
+    }
+
+    fn part2(equations: &Vec<Equation>) -> Answer {
+        let operators = [Operator::Add, Operator::Multiply, Operator::Concat];
+
+        calibration_result(equations, &operators).into()
+    }
+}
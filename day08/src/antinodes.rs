@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+use crate::position::Position;
+
+pub type Grid = FnvHashMap<Position, char>;
+pub type GroupedAntennas = BTreeMap<char, Vec<Position>>;
+pub type Antinodes = FnvHashSet<Position>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    TwoPoint,
+    Harmonic,
+}
+
+pub struct City {
+    grid: Grid,
+    grouped_antennas: GroupedAntennas,
+}
+
+impl City {
+    pub fn parse(input: &str) -> Self {
+        let grid = parse_grid(input);
+        let grouped_antennas = group_antennas_by_frequency(&grid);
+
+        Self {
+            grid,
+            grouped_antennas,
+        }
+    }
+
+    pub fn contains(&self, position: &Position) -> bool {
+        self.grid.contains_key(position)
+    }
+
+    pub fn frequencies(&self) -> impl Iterator<Item = char> + use<'_> {
+        self.grouped_antennas.keys().copied()
+    }
+
+    pub fn antinodes(&self, mode: Mode) -> Antinodes {
+        self.frequencies()
+            .flat_map(|frequency| self.frequency_antinodes(frequency, mode))
+            .collect()
+    }
+
+    pub fn frequency_antinodes(&self, frequency: char, mode: Mode) -> Antinodes {
+        let Some(antennas) = self.grouped_antennas.get(&frequency) else {
+            return Antinodes::default();
+        };
+
+        antennas
+            .iter()
+            .copied()
+            .tuple_combinations()
+            .flat_map(|pair| match mode {
+                Mode::TwoPoint => self.two_point_antinodes(pair),
+                Mode::Harmonic => self.harmonic_antinodes(pair),
+            })
+            .collect()
+    }
+
+    pub fn antinode_counts(&self, mode: Mode) -> BTreeMap<char, usize> {
+        self.frequencies()
+            .map(|frequency| (frequency, self.frequency_antinodes(frequency, mode).len()))
+            .collect()
+    }
+
+    fn two_point_antinodes(&self, (a, b): (Position, Position)) -> Vec<Position> {
+        let vector = b - a;
+
+        [a + vector.flipped(), b + vector]
+            .into_iter()
+            .filter(|position| self.contains(position))
+            .collect()
+    }
+
+    fn harmonic_antinodes(&self, (a, b): (Position, Position)) -> Vec<Position> {
+        // Stepping by the reduced vector also hits lattice points between
+        // the antennas, which the raw difference would skip over.
+        let vector = (b - a).reduced();
+        let mut positions = Vec::new();
+
+        for vector in [vector, vector.flipped()] {
+            let mut position = a;
+
+            while self.contains(&position) {
+                positions.push(position);
+                position = position + vector;
+            }
+        }
+
+        positions
+    }
+
+    pub fn render(&self, antinodes: &Antinodes) -> String {
+        let width = self.grid.keys().map(|position| position.x + 1).max();
+        let height = self.grid.keys().map(|position| position.y + 1).max();
+        let mut map = String::new();
+
+        for y in 0..height.unwrap_or(0) {
+            for x in 0..width.unwrap_or(0) {
+                let position = Position(x, y);
+                let cell = self.grid.get(&position).copied().unwrap_or(' ');
+
+                if cell == '.' && antinodes.contains(&position) {
+                    map.push('#');
+                } else {
+                    map.push(cell);
+                }
+            }
+
+            map.push('\n');
+        }
+
+        map
+    }
+}
+
+fn group_antennas_by_frequency(grid: &Grid) -> GroupedAntennas {
+    let mut grouped_antennas = GroupedAntennas::default();
+
+    for (&position, &cell) in grid.iter() {
+        if cell == '.' {
+            continue;
+        }
+
+        grouped_antennas
+            .entry(cell)
+            .or_insert(Vec::new())
+            .push(position);
+    }
+
+    for antennas in grouped_antennas.values_mut() {
+        antennas.sort();
+    }
+
+    grouped_antennas
+}
+
+fn parse_grid(input: &str) -> Grid {
+    let mut grid = FnvHashMap::default();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, ch) in line.chars().enumerate() {
+            let position = Position(x as i32, y as i32);
+
+            grid.insert(position, ch);
+        }
+    }
+
+    grid
+}
@@ -0,0 +1,4 @@
+pub mod antinodes;
+pub mod position;
+pub mod solution;
+pub mod vector;
@@ -0,0 +1,26 @@
+use utils::shape::{Shape, DIGITS, LOWERCASE, UPPERCASE};
+use utils::solution::{Answer, Solution};
+
+use crate::antinodes::{City, Mode};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = City;
+
+    fn shape() -> Shape {
+        Shape::grid(format!(".{DIGITS}{UPPERCASE}{LOWERCASE}"))
+    }
+
+    fn parse(input: &str) -> City {
+        City::parse(input)
+    }
+
+    fn part1(city: &City) -> Answer {
+        city.antinodes(Mode::TwoPoint).len().into()
+    }
+
+    fn part2(city: &City) -> Answer {
+        city.antinodes(Mode::Harmonic).len().into()
+    }
+}
@@ -1,3 +1,6 @@
+use utils::num::gcd;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Vector {
     pub x: i32,
     pub y: i32,
@@ -10,4 +13,17 @@ impl Vector {
             y: -self.y,
         }
     }
+
+    pub fn reduced(&self) -> Vector {
+        let divisor = gcd(self.x, self.y);
+
+        if divisor == 0 {
+            return *self;
+        }
+
+        Self {
+            x: self.x / divisor,
+            y: self.y / divisor,
+        }
+    }
 }
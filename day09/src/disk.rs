@@ -0,0 +1,198 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use utils::interval::{Interval, RangeSet};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Extent {
+    pub id: usize,
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Extent {
+    pub fn end(&self) -> usize {
+        self.start + self.len
+    }
+
+    pub fn interval(&self) -> Interval<usize> {
+        Interval::with_len(self.start, self.len)
+    }
+
+    pub fn checksum(&self) -> usize {
+        let positions_sum = self.start * self.len + self.len * self.len.saturating_sub(1) / 2;
+
+        self.id * positions_sum
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Gap {
+    pub start: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Disk {
+    files: Vec<Extent>,
+    gaps: Vec<Gap>,
+    size: usize,
+}
+
+impl Disk {
+    pub fn parse(disk: &str) -> Self {
+        let mut files = Vec::new();
+        let mut gaps = Vec::new();
+        let mut start = 0;
+
+        for (i, ch) in disk.trim().chars().enumerate() {
+            let len = ch.to_digit(10).unwrap() as usize;
+
+            if i % 2 == 0 {
+                files.push(Extent {
+                    id: i / 2,
+                    start,
+                    len,
+                });
+            } else if len > 0 {
+                gaps.push(Gap { start, len });
+            }
+
+            start += len;
+        }
+
+        Self {
+            files,
+            gaps,
+            size: start,
+        }
+    }
+
+    pub fn files(&self) -> &[Extent] {
+        &self.files
+    }
+
+    pub fn gaps(&self) -> &[Gap] {
+        &self.gaps
+    }
+
+    pub fn checksum(&self) -> usize {
+        self.files.iter().map(Extent::checksum).sum()
+    }
+
+    pub fn compact_blocks(&mut self) {
+        let mut gaps = self.gaps.iter().copied();
+        let mut gap = gaps.next();
+        let mut files = Vec::with_capacity(self.files.len());
+
+        for mut file in self.files.drain(..).rev() {
+            while file.len > 0 {
+                let Some(current_gap) = gap.as_mut().filter(|gap| gap.start < file.start) else {
+                    break;
+                };
+
+                let len = current_gap.len.min(file.len);
+
+                files.push(Extent {
+                    id: file.id,
+                    start: current_gap.start,
+                    len,
+                });
+
+                current_gap.start += len;
+                current_gap.len -= len;
+                file.len -= len;
+
+                if current_gap.len == 0 {
+                    gap = gaps.next();
+                }
+            }
+
+            if file.len > 0 {
+                files.push(file);
+            }
+        }
+
+        self.files = files;
+        self.rebuild_gaps();
+    }
+
+    pub fn compact_files(&mut self) {
+        let max_gap_len = self.gaps.iter().map(|gap| gap.len).max().unwrap_or(0);
+        let mut gaps_by_len = vec![BinaryHeap::new(); max_gap_len + 1];
+
+        for gap in &self.gaps {
+            gaps_by_len[gap.len].push(Reverse(gap.start));
+        }
+
+        for file in self.files.iter_mut().rev() {
+            if file.len == 0 {
+                continue;
+            }
+
+            let leftmost_gap = gaps_by_len
+                .iter()
+                .enumerate()
+                .skip(file.len)
+                .filter_map(|(len, gaps)| gaps.peek().map(|Reverse(start)| (*start, len)))
+                .min();
+
+            let Some((gap_start, gap_len)) = leftmost_gap else {
+                continue;
+            };
+
+            if gap_start >= file.start {
+                continue;
+            }
+
+            gaps_by_len[gap_len].pop();
+            file.start = gap_start;
+
+            let remaining_len = gap_len - file.len;
+
+            if remaining_len > 0 {
+                gaps_by_len[remaining_len].push(Reverse(gap_start + file.len));
+            }
+        }
+
+        self.rebuild_gaps();
+    }
+
+    fn rebuild_gaps(&mut self) {
+        self.files.sort_by_key(|file| file.start);
+
+        let used = self
+            .files
+            .iter()
+            .map(Extent::interval)
+            .collect::<RangeSet<_>>();
+
+        self.gaps = used
+            .gaps(0..self.size)
+            .iter()
+            .map(|gap| Gap {
+                start: gap.start,
+                len: gap.len(),
+            })
+            .collect();
+    }
+}
+
+impl fmt::Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut blocks = vec!['.'; self.size];
+
+        for file in &self.files {
+            // Ids wrap around on disks with more than ten files
+            let id = char::from_digit((file.id % 10) as u32, 10).unwrap();
+
+            blocks[file.start..file.end()].fill(id);
+        }
+
+        for block in blocks {
+            write!(f, "{block}")?;
+        }
+
+        Ok(())
+    }
+}
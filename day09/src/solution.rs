@@ -0,0 +1,32 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::disk::Disk;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = Disk;
+
+    fn shape() -> Shape {
+        Shape::lines(r"\d+")
+    }
+
+    fn parse(input: &str) -> Disk {
+        Disk::parse(input)
+    }
+
+    fn part1(disk: &Disk) -> Answer {
+        let mut disk = disk.clone();
+
+        disk.compact_blocks();
+        disk.checksum().into()
+    }
+
+    fn part2(disk: &Disk) -> Answer {
+        let mut disk = disk.clone();
+
+        disk.compact_files();
+        disk.checksum().into()
+    }
+}
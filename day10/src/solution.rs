@@ -0,0 +1,28 @@
+use utils::shape::{Shape, DIGITS};
+use utils::solution::{Answer, Solution};
+
+use crate::trails::{Hiking, Mountain, Trails};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Trails;
+
+    fn shape() -> Shape {
+        Shape::grid(format!("{DIGITS}."))
+    }
+
+    fn parse(input: &str) -> Trails {
+        let mountain = Mountain::parse(input);
+
+        Trails::new(&mountain, Hiking::default())
+    }
+
+    fn part1(trails: &Trails) -> Answer {
+        trails.total_score().into()
+    }
+
+    fn part2(trails: &Trails) -> Answer {
+        trails.total_rating().into()
+    }
+}
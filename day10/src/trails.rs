@@ -0,0 +1,149 @@
+use utils::bits::BitSet;
+
+pub type Pos = (isize, isize);
+
+pub struct Mountain {
+    width: isize,
+    height: isize,
+    elevations: Vec<Option<u32>>,
+}
+
+impl Mountain {
+    pub fn parse(input: &str) -> Self {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let height = lines.len();
+        let elevations = lines
+            .iter()
+            .flat_map(|line| line.chars().map(|ch| ch.to_digit(10)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(elevations.len(), width * height, "map is not rectangular");
+
+        Self {
+            width: width as isize,
+            height: height as isize,
+            elevations,
+        }
+    }
+
+    pub fn elevation(&self, pos: Pos) -> Option<u32> {
+        self.index(pos).and_then(|index| self.elevations[index])
+    }
+
+    fn index(&self, (x, y): Pos) -> Option<usize> {
+        if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+            return None;
+        }
+
+        Some((y * self.width + x) as usize)
+    }
+
+    fn pos(&self, index: usize) -> Pos {
+        let index = index as isize;
+
+        (index % self.width, index / self.width)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Hiking {
+    pub trailhead_elevation: u32,
+    pub summit_elevation: u32,
+    pub step: u32,
+}
+
+impl Default for Hiking {
+    fn default() -> Self {
+        Self {
+            trailhead_elevation: 0,
+            summit_elevation: 9,
+            step: 1,
+        }
+    }
+}
+
+pub struct Trails {
+    trailheads: Vec<usize>,
+    reachable_summits: Vec<BitSet>,
+    paths: Vec<usize>,
+}
+
+impl Trails {
+    pub fn new(mountain: &Mountain, hiking: Hiking) -> Self {
+        assert!(hiking.step > 0, "trails must climb");
+
+        let num_cells = mountain.elevations.len();
+        let summits = (0..num_cells)
+            .filter(|&index| mountain.elevations[index] == Some(hiking.summit_elevation))
+            .collect::<Vec<_>>();
+        let trailheads = (0..num_cells)
+            .filter(|&index| mountain.elevations[index] == Some(hiking.trailhead_elevation))
+            .collect::<Vec<_>>();
+        let mut reachable_summits = vec![BitSet::with_capacity(summits.len()); num_cells];
+        let mut paths = vec![0; num_cells];
+
+        for (bit, &summit) in summits.iter().enumerate() {
+            reachable_summits[summit].insert(bit);
+            paths[summit] = 1;
+        }
+
+        // The map is a DAG because trails only ever climb, so visiting cells
+        // from the top down guarantees every successor is done beforehand.
+        let mut cells = (0..num_cells)
+            .filter_map(|index| Some((mountain.elevations[index]?, index)))
+            .filter(|(elevation, _)| *elevation < hiking.summit_elevation)
+            .collect::<Vec<_>>();
+
+        cells.sort_unstable_by(|a, b| b.cmp(a));
+
+        for (elevation, index) in cells {
+            for neighbour in neighbours(mountain.pos(index)) {
+                if mountain.elevation(neighbour) != Some(elevation + hiking.step) {
+                    continue;
+                }
+
+                let neighbour = mountain.index(neighbour).unwrap();
+                let neighbour_summits = reachable_summits[neighbour].clone();
+
+                reachable_summits[index] |= &neighbour_summits;
+
+                paths[index] += paths[neighbour];
+            }
+        }
+
+        Self {
+            trailheads,
+            reachable_summits,
+            paths,
+        }
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = usize> + use<'_> {
+        self.trailheads.iter().copied()
+    }
+
+    fn score(&self, trailhead: usize) -> usize {
+        self.reachable_summits[trailhead].len()
+    }
+
+    fn rating(&self, trailhead: usize) -> usize {
+        self.paths[trailhead]
+    }
+
+    pub fn total_score(&self) -> usize {
+        self.trailheads()
+            .map(|trailhead| self.score(trailhead))
+            .sum()
+    }
+
+    pub fn total_rating(&self) -> usize {
+        self.trailheads()
+            .map(|trailhead| self.rating(trailhead))
+            .sum()
+    }
+}
+
+fn neighbours((x, y): Pos) -> impl Iterator<Item = Pos> {
+    [(x - 1, y), (x, y - 1), (x, y + 1), (x + 1, y)].into_iter()
+}
@@ -0,0 +1,26 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::stones::{count_stones_after_blinks, parse_stones, Stone};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Stone>;
+
+    fn shape() -> Shape {
+        Shape::numbers(" ")
+    }
+
+    fn parse(input: &str) -> Vec<Stone> {
+        parse_stones(input)
+    }
+
+    fn part1(stones: &Vec<Stone>) -> Answer {
+        count_stones_after_blinks(stones, 25).into()
+    }
+
+    fn part2(stones: &Vec<Stone>) -> Answer {
+        count_stones_after_blinks(stones, 75).into()
+    }
+}
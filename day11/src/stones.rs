@@ -0,0 +1,47 @@
+use utils::memoize;
+use utils::num::{num_digits, split_digits};
+
+pub type Stone = u64;
+
+pub fn parse_stones(input: &str) -> Vec<Stone> {
+    input
+        .split_whitespace()
+        .map(|n| n.parse::<Stone>().unwrap())
+        .collect()
+}
+
+pub fn count_stones_after_blinks(stones: &[Stone], blinks: u32) -> usize {
+    stones
+        .iter()
+        .map(|&stone| count_stones(stone, blinks))
+        .sum()
+}
+
+memoize! {
+    pub fn count_stones(stone: Stone, blinks: u32) -> usize {
+        if blinks == 0 {
+            return 1;
+        }
+
+        match blink(stone) {
+            (stone, None) => count_stones(stone, blinks - 1),
+            (left, Some(right)) => count_stones(left, blinks - 1) + count_stones(right, blinks - 1),
+        }
+    }
+}
+
+pub fn blink(stone: Stone) -> (Stone, Option<Stone>) {
+    if stone == 0 {
+        return (1, None);
+    }
+
+    let num_digits = num_digits(stone);
+
+    if num_digits.is_multiple_of(2) {
+        let (left, right) = split_digits(stone, num_digits / 2);
+
+        return (left, Some(right));
+    }
+
+    (stone * 2024, None)
+}
@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day11::solution::Day11;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 11, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day11::solve(input, 2)
+}
@@ -0,0 +1,240 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use utils::dsu::KeyedUnionFind;
+use utils::image::{LabelColors, Picture};
+use utils::viz::Color;
+
+pub type Plot = (isize, isize);
+pub type Label = char;
+
+pub struct Garden {
+    width: isize,
+    height: isize,
+    labels: Vec<Label>,
+}
+
+impl Garden {
+    pub fn parse(input: &str) -> Self {
+        let lines = input.lines().map(str::trim).collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let height = lines.len();
+        let labels = lines
+            .iter()
+            .flat_map(|line| line.chars())
+            .collect::<Vec<_>>();
+
+        assert_eq!(labels.len(), width * height, "garden is not rectangular");
+
+        Self {
+            width: width as isize,
+            height: height as isize,
+            labels,
+        }
+    }
+
+    pub fn get(&self, (x, y): Plot) -> Option<Label> {
+        self.index((x, y)).map(|index| self.labels[index])
+    }
+
+    pub fn plots(&self) -> impl Iterator<Item = Plot> + use<'_> {
+        (0..self.height).flat_map(|y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    fn index(&self, (x, y): Plot) -> Option<usize> {
+        if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+            return None;
+        }
+
+        Some((y * self.width + x) as usize)
+    }
+
+    // Regions come in the order of their first plot, row by row
+    pub fn regions(&self) -> Vec<Region> {
+        let mut plots = KeyedUnionFind::new();
+
+        for plot @ (x, y) in self.plots() {
+            let label = self.get(plot);
+
+            plots.insert(plot);
+
+            // Joining each plot with the ones above and to the left covers every pair once
+            for neighbour in [(x, y - 1), (x - 1, y)] {
+                if self.get(neighbour) == label {
+                    plots.union(plot, neighbour);
+                }
+            }
+        }
+
+        plots
+            .components()
+            .into_iter()
+            .map(|component| {
+                let first = *component[0];
+                let mut region = Region::new(self.get(first).unwrap(), first);
+
+                for &plot in component {
+                    region.add_plot(plot, self);
+                }
+
+                region
+            })
+            .collect()
+    }
+}
+
+pub struct Region {
+    pub label: Label,
+    pub plots: Vec<Plot>,
+    pub perimeter: usize,
+    pub sides: usize,
+    pub top_left: Plot,
+    pub bottom_right: Plot,
+}
+
+impl Region {
+    fn new(label: Label, plot: Plot) -> Self {
+        Self {
+            label,
+            plots: Vec::new(),
+            perimeter: 0,
+            sides: 0,
+            top_left: plot,
+            bottom_right: plot,
+        }
+    }
+
+    fn add_plot(&mut self, plot @ (x, y): Plot, garden: &Garden) {
+        let is_inside = |(xd, yd): (isize, isize)| garden.get((x + xd, y + yd)) == Some(self.label);
+
+        self.plots.push(plot);
+
+        self.perimeter += neighbours((0, 0))
+            .filter(|&direction| !is_inside(direction))
+            .count();
+
+        // Every side of a region starts and ends in a corner,
+        // so counting the corners also counts the sides.
+        for (xd, yd) in [(-1, -1), (1, -1), (1, 1), (-1, 1)] {
+            let horizontal = is_inside((xd, 0));
+            let vertical = is_inside((0, yd));
+            let diagonal = is_inside((xd, yd));
+            let is_convex_corner = !horizontal && !vertical;
+            let is_concave_corner = horizontal && vertical && !diagonal;
+
+            if is_convex_corner || is_concave_corner {
+                self.sides += 1;
+            }
+        }
+
+        self.top_left = (self.top_left.0.min(x), self.top_left.1.min(y));
+        self.bottom_right = (self.bottom_right.0.max(x), self.bottom_right.1.max(y));
+    }
+
+    pub fn area(&self) -> usize {
+        self.plots.len()
+    }
+
+    pub fn price(&self) -> usize {
+        self.area() * self.perimeter
+    }
+
+    pub fn bulk_discount_price(&self) -> usize {
+        self.area() * self.sides
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plots = self.plots.iter().copied().collect::<BTreeSet<_>>();
+        let (min_x, min_y) = self.top_left;
+        let (max_x, max_y) = self.bottom_right;
+        let width = (2 * (max_x - min_x) + 3) as usize;
+        let height = (2 * (max_y - min_y) + 3) as usize;
+        let mut canvas = vec![vec![' '; width]; height];
+
+        writeln!(
+            f,
+            "{}: area {}, perimeter {}, sides {}",
+            self.label,
+            self.area(),
+            self.perimeter,
+            self.sides
+        )?;
+
+        for &(x, y) in &plots {
+            let column = (2 * (x - min_x) + 1) as usize;
+            let row = (2 * (y - min_y) + 1) as usize;
+
+            canvas[row][column] = self.label;
+
+            for (xd, yd) in neighbours((0, 0)) {
+                if plots.contains(&(x + xd, y + yd)) {
+                    continue;
+                }
+
+                let fence = if xd == 0 { '-' } else { '|' };
+                let fence_column = column.wrapping_add_signed(xd);
+                let fence_row = row.wrapping_add_signed(yd);
+
+                canvas[fence_row][fence_column] = fence;
+            }
+        }
+
+        for row in (0..height).step_by(2) {
+            for column in (0..width).step_by(2) {
+                let has_horizontal_fence = [column.wrapping_sub(1), column + 1]
+                    .into_iter()
+                    .any(|column| canvas[row].get(column) == Some(&'-'));
+                let has_vertical_fence = [row.wrapping_sub(1), row + 1]
+                    .into_iter()
+                    .any(|row| canvas.get(row).map(|row| row[column]) == Some('|'));
+
+                if has_horizontal_fence || has_vertical_fence {
+                    canvas[row][column] = '+';
+                }
+            }
+        }
+
+        for row in canvas {
+            writeln!(f, "{}", row.into_iter().collect::<String>().trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn print_fences(regions: &[Region]) {
+    for region in regions {
+        println!("{region}");
+    }
+}
+
+// Regions are coloured by index, as separate regions often share a label
+pub fn picture(regions: &[Region]) -> Picture {
+    let width = regions.iter().map(|region| region.bottom_right.0 + 1).max();
+    let height = regions.iter().map(|region| region.bottom_right.1 + 1).max();
+    let mut picture = Picture::new(
+        width.unwrap_or(0) as usize,
+        height.unwrap_or(0) as usize,
+        Color::Black,
+    );
+    let colors = LabelColors::new();
+
+    for (index, region) in regions.iter().enumerate() {
+        for &plot in &region.plots {
+            picture.set(plot, colors.get(&index));
+        }
+    }
+
+    picture
+}
+
+fn neighbours((x, y): Plot) -> impl Iterator<Item = Plot> {
+    [
+        (x, y - 1), // up
+        (x, y + 1), // down
+        (x - 1, y), // left
+        (x + 1, y), // right
+    ]
+    .into_iter()
+}
@@ -0,0 +1,30 @@
+use utils::shape::{Shape, UPPERCASE};
+use utils::solution::{Answer, Solution};
+
+use crate::regions::{Garden, Region};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Region>;
+
+    fn shape() -> Shape {
+        Shape::grid(UPPERCASE)
+    }
+
+    fn parse(input: &str) -> Vec<Region> {
+        Garden::parse(input).regions()
+    }
+
+    fn part1(regions: &Vec<Region>) -> Answer {
+        regions.iter().map(Region::price).sum::<usize>().into()
+    }
+
+    fn part2(regions: &Vec<Region>) -> Answer {
+        regions
+            .iter()
+            .map(Region::bulk_discount_price)
+            .sum::<usize>()
+            .into()
+    }
+}
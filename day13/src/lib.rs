@@ -0,0 +1,2 @@
+pub mod machines;
+pub mod solution;
@@ -0,0 +1,43 @@
+use utils::num::solve_2x2;
+use utils::parse::{sections, uint, Parser};
+use utils::StrExt;
+
+pub fn parse_machines(input: &str) -> Vec<Machine> {
+    let button = |label| (label, "X+", uint(), ", Y+", uint()).map(|(_, _, x, _, y)| Vec2 { x, y });
+    let prize = ("Prize: X=", uint(), ", Y=", uint()).map(|(_, x, _, y)| Vec2 { x, y });
+    let machine = (
+        button("Button A: "),
+        '\n',
+        button("Button B: "),
+        '\n',
+        prize,
+    )
+        .map(|(a, _, b, _, prize)| Machine { a, b, prize });
+
+    input.parse_with(sections(machine))
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    a: Vec2,
+    b: Vec2,
+    prize: Vec2,
+}
+
+impl Machine {
+    // Buttons never move the claw in the same direction, so each prize
+    // can only be won with one combination of presses
+    pub fn tokens(&self) -> Option<i64> {
+        let buttons = [[self.a.x, self.b.x], [self.a.y, self.b.y]];
+        let (a, b) = solve_2x2(buttons, [self.prize.x, self.prize.y])?;
+        let presses = 0..=100;
+
+        (presses.contains(&a) && presses.contains(&b)).then_some(a * 3 + b)
+    }
+}
+
+#[derive(Debug)]
+struct Vec2 {
+    x: i64,
+    y: i64,
+}
@@ -0,0 +1,21 @@
+use utils::solution::{Answer, Solution};
+
+use crate::machines::{parse_machines, Machine};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Vec<Machine> {
+        parse_machines(input)
+    }
+
+    fn part1(machines: &Vec<Machine>) -> Answer {
+        machines
+            .iter()
+            .filter_map(|machine| machine.tokens())
+            .sum::<i64>()
+            .into()
+    }
+}
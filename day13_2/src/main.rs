@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day13::solution::Day13;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 13, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day13::solve(input, 2)
+}
@@ -0,0 +1,3 @@
+pub mod lobby;
+pub mod solution;
+pub mod vec2;
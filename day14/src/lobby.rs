@@ -0,0 +1,70 @@
+use regex::Regex;
+
+use crate::vec2::Vec2;
+
+pub struct Lobby {
+    dimensions: Vec2,
+    robots: Vec<Robot>,
+}
+
+impl Lobby {
+    pub fn parse(input: &str, dimensions: Vec2) -> Self {
+        let robots = input.lines().map(Robot::parse).collect();
+
+        Self { dimensions, robots }
+    }
+
+    pub fn safety_factor(&self, num_iterations: isize) -> usize {
+        let dimensions = self.dimensions;
+        let positions = self
+            .robots
+            .iter()
+            .map(|robot| (robot.position + robot.velocity * num_iterations) % dimensions)
+            .collect::<Vec<_>>();
+
+        let center = dimensions / 2;
+        let top_left = positions
+            .iter()
+            .filter(|position| position.x < center.x && position.y < center.y)
+            .count();
+        let top_right = positions
+            .iter()
+            .filter(|position| position.x > center.x && position.y < center.y)
+            .count();
+        let bottom_left = positions
+            .iter()
+            .filter(|position| position.x < center.x && position.y > center.y)
+            .count();
+        let bottom_right = positions
+            .iter()
+            .filter(|position| position.x > center.x && position.y > center.y)
+            .count();
+
+        top_left * top_right * bottom_left * bottom_right
+    }
+}
+
+struct Robot {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+impl Robot {
+    fn parse(robot: &str) -> Self {
+        let re =
+            Regex::new(r"^p=(?P<px>-?\d+),(?P<py>-?\d+) v=(?P<vx>-?\d+),(?P<vy>-?\d+)$").unwrap();
+
+        let caps = re.captures(robot).unwrap();
+
+        Self {
+            position: Vec2 {
+                x: caps["px"].parse().unwrap(),
+                y: caps["py"].parse().unwrap(),
+            },
+            velocity: Vec2 {
+                x: caps["vx"].parse().unwrap(),
+                y: caps["vy"].parse().unwrap(),
+            },
+        }
+    }
+}
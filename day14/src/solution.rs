@@ -0,0 +1,24 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+
+use crate::lobby::Lobby;
+use crate::vec2::Vec2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Lobby;
+
+    fn shape() -> Shape {
+        Shape::lines(r"p=\d+,\d+ v=-?\d+,-?\d+")
+    }
+
+    // The lobby of the puzzle's example is smaller, at 11x7 tiles
+    fn parse(input: &str) -> Lobby {
+        Lobby::parse(input, Vec2::new(101, 103))
+    }
+
+    fn part1(lobby: &Lobby) -> Answer {
+        lobby.safety_factor(100).into()
+    }
+}
@@ -0,0 +1,98 @@
+use std::ops;
+
+use utils::num::modulo;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vec2 {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn splat(n: isize) -> Self {
+        Self::new(n, n)
+    }
+}
+
+impl ops::Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl ops::Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl ops::Div for Vec2 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+}
+
+impl ops::Div<isize> for Vec2 {
+    type Output = Self;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        self / Vec2::splat(rhs)
+    }
+}
+
+impl ops::Rem for Vec2 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self {
+            x: modulo(self.x, rhs.x),
+            y: modulo(self.y, rhs.y),
+        }
+    }
+}
+
+impl ops::Rem<isize> for Vec2 {
+    type Output = Self;
+
+    fn rem(self, rhs: isize) -> Self::Output {
+        self % Vec2::splat(rhs)
+    }
+}
+
+impl ops::Mul for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
+
+impl ops::Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        self * Vec2::splat(rhs)
+    }
+}
@@ -0,0 +1,18 @@
+use day14::lobby::Lobby;
+use day14::vec2::Vec2;
+use utils::fixture::Fixture;
+
+// Examples live in `fixtures/`, written by `scaffold examples` or by hand. They take
+// place in a lobby of 11x7 tiles instead of the real one, so they bypass `Day14`.
+#[test]
+fn examples() {
+    for fixture in Fixture::load_all(env!("CARGO_MANIFEST_DIR")) {
+        let lobby = Lobby::parse(&fixture.input, Vec2::new(11, 7));
+
+        if let Some(expected) = fixture.answer(1) {
+            let answer = lobby.safety_factor(100).to_string();
+
+            assert_eq!(answer, expected, "part 1 of {}", fixture.name);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day14::solution::Day14;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 14, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day14::solve(input, 2)
+}
@@ -0,0 +1,32 @@
+use utils::shape::Shape;
+use utils::solution::{Answer, Solution};
+use utils::viz::Disabled;
+
+use crate::warehouse::{parse_input, widen, Dir, Warehouse};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = (&'a str, Vec<Dir>);
+
+    fn shape() -> Shape {
+        Shape::sections([Shape::grid("#.O@"), Shape::chars("<>^v")])
+    }
+
+    fn parse(input: &str) -> (&str, Vec<Dir>) {
+        parse_input(input)
+    }
+
+    fn part1((grid, dirs): &(&str, Vec<Dir>)) -> Answer {
+        gps_coordinates_sum_after(Warehouse::parse(grid), dirs)
+    }
+
+    fn part2((grid, dirs): &(&str, Vec<Dir>)) -> Answer {
+        gps_coordinates_sum_after(Warehouse::parse(&widen(grid)), dirs)
+    }
+}
+
+fn gps_coordinates_sum_after(mut warehouse: Warehouse, dirs: &[Dir]) -> Answer {
+    warehouse.simulate(dirs, &mut Disabled);
+    warehouse.gps_coordinates_sum().into()
+}
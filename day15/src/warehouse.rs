@@ -0,0 +1,299 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fmt;
+
+use utils::viz::{Color, Frame, Palette, Visualizer};
+
+pub type Pos = (isize, isize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Object {
+    pub position: Pos,
+    pub footprint: Vec<(Pos, char)>,
+}
+
+impl Object {
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, char)> + use<'_> {
+        let (x, y) = self.position;
+
+        self.footprint
+            .iter()
+            .map(move |&((xd, yd), glyph)| ((x + xd, y + yd), glyph))
+    }
+
+    pub fn gps_coordinate(&self) -> isize {
+        let (x, y) = self.position;
+
+        x + 100 * y
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    walls: BTreeSet<Pos>,
+    objects: Vec<Object>,
+    occupied: BTreeMap<Pos, usize>,
+    robot: Pos,
+    width: isize,
+    height: isize,
+}
+
+impl Warehouse {
+    pub fn parse(grid: &str) -> Self {
+        let mut walls = BTreeSet::new();
+        let mut objects = Vec::new();
+        let mut robot = None;
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in grid.lines().enumerate() {
+            let y = y as isize;
+            let mut chars = line.chars().zip(0..).peekable();
+
+            while let Some((ch, x)) = chars.next() {
+                let pos = (x, y);
+
+                match ch {
+                    '.' => {}
+                    '#' => {
+                        walls.insert(pos);
+                    }
+                    '@' => robot = Some(pos),
+                    'O' => objects.push(Object {
+                        position: pos,
+                        footprint: vec![((0, 0), 'O')],
+                    }),
+                    '[' => {
+                        let mut footprint = vec![((0, 0), '[')];
+
+                        while let Some((ch, x)) = chars.next_if(|(ch, _)| *ch != '#') {
+                            footprint.push(((x - pos.0, 0), ch));
+
+                            if ch == ']' {
+                                break;
+                            }
+                        }
+
+                        assert_eq!(footprint.last().unwrap().1, ']', "unclosed box at {pos:?}");
+
+                        objects.push(Object {
+                            position: pos,
+                            footprint,
+                        });
+                    }
+                    ch => unreachable!("ch is {ch:?}"),
+                }
+
+                width = width.max(x + 1);
+            }
+
+            height = y + 1;
+        }
+
+        let mut warehouse = Self {
+            walls,
+            objects: Vec::new(),
+            occupied: BTreeMap::new(),
+            robot: robot.expect("warehouse has no robot"),
+            width,
+            height,
+        };
+
+        for object in objects {
+            warehouse.add_object(object);
+        }
+
+        warehouse
+    }
+
+    pub fn add_object(&mut self, object: Object) {
+        let index = self.objects.len();
+
+        for (pos, _) in object.cells() {
+            let previous = self.occupied.insert(pos, index);
+
+            assert!(previous.is_none(), "objects overlap at {pos:?}");
+        }
+
+        self.objects.push(object);
+    }
+
+    pub fn robot(&self) -> Pos {
+        self.robot
+    }
+
+    pub fn objects(&self) -> &[Object] {
+        &self.objects
+    }
+
+    pub fn gps_coordinates_sum(&self) -> isize {
+        self.objects.iter().map(Object::gps_coordinate).sum()
+    }
+
+    pub fn simulate(&mut self, dirs: &[Dir], viz: &mut impl Visualizer) {
+        viz.emit(|| self.frame().with_title("Initial state"));
+
+        for &dir in dirs {
+            self.step(dir);
+
+            viz.emit(|| self.frame().with_title(format!("Move {}", dir.as_char())));
+        }
+    }
+
+    pub fn frame(&self) -> Frame {
+        let palette = Palette::new()
+            .fg('#', Color::Gray)
+            .fg('O', Color::Yellow)
+            .fg('[', Color::Yellow)
+            .fg(']', Color::Yellow)
+            .fg('@', Color::Red);
+
+        Frame::from_text(&self.to_string(), &palette)
+    }
+
+    pub fn step(&mut self, dir: Dir) -> bool {
+        let Some(pushed_objects) = self.pushed_objects(dir) else {
+            return false;
+        };
+
+        for &index in &pushed_objects {
+            for (pos, _) in self.objects[index].cells() {
+                self.occupied.remove(&pos);
+            }
+        }
+
+        for &index in &pushed_objects {
+            let object = &mut self.objects[index];
+
+            object.position = dir.apply_to(object.position);
+
+            for (pos, _) in object.cells() {
+                self.occupied.insert(pos, index);
+            }
+        }
+
+        self.robot = dir.apply_to(self.robot);
+
+        true
+    }
+
+    fn pushed_objects(&self, dir: Dir) -> Option<BTreeSet<usize>> {
+        let mut pushed_objects = BTreeSet::new();
+        let mut queue = VecDeque::from([dir.apply_to(self.robot)]);
+
+        while let Some(pos) = queue.pop_front() {
+            if self.walls.contains(&pos) {
+                return None;
+            }
+
+            let Some(&index) = self.occupied.get(&pos) else {
+                continue;
+            };
+
+            if !pushed_objects.insert(index) {
+                continue;
+            }
+
+            for (pos, _) in self.objects[index].cells() {
+                let next_pos = dir.apply_to(pos);
+
+                if self.occupied.get(&next_pos) != Some(&index) {
+                    queue.push_back(next_pos);
+                }
+            }
+        }
+
+        Some(pushed_objects)
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut grid = vec![vec!['.'; self.width as usize]; self.height as usize];
+        let mut draw = |(x, y): Pos, glyph: char| {
+            if let Some(cell) = grid
+                .get_mut(y as usize)
+                .and_then(|row| row.get_mut(x as usize))
+            {
+                *cell = glyph;
+            }
+        };
+
+        for &pos in &self.walls {
+            draw(pos, '#');
+        }
+
+        for (pos, glyph) in self.objects.iter().flat_map(Object::cells) {
+            draw(pos, glyph);
+        }
+
+        draw(self.robot, '@');
+
+        for row in grid {
+            writeln!(f, "{}", row.into_iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn widen(grid: &str) -> String {
+    grid.chars()
+        .map(|ch| match ch {
+            '#' => "##",
+            'O' => "[]",
+            '.' => "..",
+            '@' => "@.",
+            '\n' => "\n",
+            ch => unreachable!("ch is {ch:?}"),
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> (&str, Vec<Dir>) {
+    let (grid, dirs) = input.split_once("\n\n").unwrap();
+    let dirs = dirs
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(Dir::parse)
+        .collect::<Vec<_>>();
+
+    (grid, dirs)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub fn parse(dir: char) -> Self {
+        match dir {
+            '^' => Self::Up,
+            'v' => Self::Down,
+            '<' => Self::Left,
+            '>' => Self::Right,
+            _ => unimplemented!("dir: {dir:?}"),
+        }
+    }
+
+    pub fn as_char(&self) -> char {
+        match self {
+            Dir::Up => '^',
+            Dir::Down => 'v',
+            Dir::Left => '<',
+            Dir::Right => '>',
+        }
+    }
+
+    pub fn apply_to(&self, (x, y): Pos) -> Pos {
+        match self {
+            Dir::Up => (x, y - 1),
+            Dir::Down => (x, y + 1),
+            Dir::Left => (x - 1, y),
+            Dir::Right => (x + 1, y),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day16::solution::Day16;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 16, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day16::solve(input, 2)
+}
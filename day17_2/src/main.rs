@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day17::solution::Day17;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 17, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day17::solve(input, 2)
+}
@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day18::solution::Day18;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 18, 1)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day18::solve(input, 1)
+}
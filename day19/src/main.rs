@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day19::solution::Day19;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 19, 1)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day19::solve(input, 1)
+}
@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day20::solution::Day20;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 20, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day20::solve(input, 2)
+}
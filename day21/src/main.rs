@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day21::solution::Day21;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 21, 1)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day21::solve(input, 1)
+}
@@ -0,0 +1,12 @@
+use utils::solution::Solution;
+
+pub struct Day21;
+
+// `part1` and `part2` return an `Answer`, and count as unsolved until they are added
+impl Solution for Day21 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+}
@@ -0,0 +1,12 @@
+use utils::solution::Solution;
+
+pub struct Day22;
+
+// `part1` and `part2` return an `Answer`, and count as unsolved until they are added
+impl Solution for Day22 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+}
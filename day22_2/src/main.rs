@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day22::solution::Day22;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 22, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day22::solve(input, 2)
+}
@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day23::solution::Day23;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 23, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day23::solve(input, 2)
+}
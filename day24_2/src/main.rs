@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day24::solution::Day24;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 24, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day24::solve(input, 2)
+}
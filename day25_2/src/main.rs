@@ -1,6 +1,8 @@
 use aoc::aoc;
+use day25::solution::Day25;
+use utils::solution::{Answer, Solution};
 
 #[aoc(2024, 25, 2)]
-fn main(input: &str) -> i32 {
-    unimplemented!()
-}
\ No newline at end of file
+fn main(input: &str) -> Answer {
+    Day25::solve(input, 2)
+}
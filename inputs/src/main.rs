@@ -0,0 +1,158 @@
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use inputs::store::{InputStore, Status, DAYS};
+
+const USAGE: &str = "\
+usage:
+    inputs path <year> <day> [--root <dir>]
+    inputs status <year> [--root <dir>]
+    inputs record <year> [--force] [--root <dir>]
+    inputs import <year> <dir or tarball> [--root <dir>]
+
+The store defaults to the `aoc` crate's download cache.
+`status` fails if any input is missing or was altered after being stored.
+`record` normalizes inputs that are already in place and records their hashes.
+Altered inputs keep their recorded hashes unless `--force` is given.";
+
+struct Options {
+    command: Command,
+    root: Option<PathBuf>,
+}
+
+enum Command {
+    Path { year: u32, day: u32 },
+    Status { year: u32 },
+    Record { year: u32, force: bool },
+    Import { year: u32, source: PathBuf },
+}
+
+fn main() -> ExitCode {
+    let Some(options) = parse_args(env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let Some(root) = options.root.or_else(InputStore::default_root) else {
+        eprintln!("error: no cache directory on this platform, pass --root");
+        return ExitCode::FAILURE;
+    };
+
+    match run(&InputStore::new(root), options.command) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Option<Options> {
+    let mut positional = Vec::new();
+    let mut root = None;
+    let mut force = false;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = Some(args.next()?.into()),
+            "--force" => force = true,
+            _ if arg.starts_with("--") => return None,
+            _ => positional.push(arg),
+        }
+    }
+
+    let command = match positional.as_slice() {
+        [command, year, day] if command == "path" => Command::Path {
+            year: year.parse().ok()?,
+            day: day.parse().ok().filter(|day| DAYS.contains(day))?,
+        },
+        [command, year] if command == "status" => Command::Status {
+            year: year.parse().ok()?,
+        },
+        [command, year] if command == "record" => Command::Record {
+            year: year.parse().ok()?,
+            force,
+        },
+        [command, year, source] if command == "import" => Command::Import {
+            year: year.parse().ok()?,
+            source: source.into(),
+        },
+        _ => return None,
+    };
+
+    if force && !matches!(command, Command::Record { .. }) {
+        return None;
+    }
+
+    Some(Options { command, root })
+}
+
+fn run(store: &InputStore, command: Command) -> io::Result<bool> {
+    match command {
+        Command::Path { year, day } => {
+            println!("{}", store.path(year, day).display());
+            Ok(true)
+        }
+        Command::Status { year } => status(store, year),
+        Command::Record { year, force } => record(store, year, force),
+        Command::Import { year, source } => {
+            let imported = if source.is_dir() {
+                store.import_dir(year, &source)?
+            } else {
+                store.import_tarball(year, &source)?
+            };
+
+            println!("imported days {imported:?}");
+            status(store, year)
+        }
+    }
+}
+
+// Recorded hashes only change with `force`, so that accidental edits don't get approved
+fn record(store: &InputStore, year: u32, force: bool) -> io::Result<bool> {
+    let mut complete = true;
+
+    for (day, status) in store.year_status(year)? {
+        match status {
+            Status::Missing | Status::Verified => {}
+            Status::Altered { .. } if !force => {
+                complete = false;
+                println!(
+                    "day {day:>2}: altered, kept the recorded hash (pass --force to replace it)"
+                );
+            }
+            Status::Unrecorded | Status::Unnormalized | Status::Altered { .. } => {
+                let hash = store.record(year, day)?;
+
+                println!("day {day:>2}: recorded {hash:016x}");
+            }
+        }
+    }
+
+    Ok(complete)
+}
+
+fn status(store: &InputStore, year: u32) -> io::Result<bool> {
+    let mut complete = true;
+
+    for (day, status) in store.year_status(year)? {
+        let description = match status {
+            Status::Missing => "missing".to_string(),
+            Status::Unrecorded => "present, hash not recorded".to_string(),
+            Status::Unnormalized => "present, needs `record` to fix line endings".to_string(),
+            Status::Verified => "ok".to_string(),
+            Status::Altered { recorded, actual } => {
+                format!("altered, recorded hash {recorded:016x} but found {actual:016x}")
+            }
+        };
+
+        complete &= matches!(status, Status::Verified | Status::Unrecorded);
+        println!("day {day:>2}: {description}");
+    }
+
+    Ok(complete)
+}
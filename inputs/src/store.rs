@@ -0,0 +1,405 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::hash::Hasher;
+use std::io::{self, BufReader, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use fnv::FnvHasher;
+
+pub const DAYS: std::ops::RangeInclusive<u32> = 1..=25;
+
+const CHECKSUMS: &str = "checksums";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Missing,
+    // Present, but stored without going through the store (e.g. downloaded by `aoc`)
+    Unrecorded,
+    // Present and unrecorded, with `\r\n` line endings or no final newline
+    Unnormalized,
+    Verified,
+    Altered { recorded: u64, actual: u64 },
+}
+
+// Uses the same layout as the `aoc` crate's download cache, so inputs fetched
+// by a solution and inputs imported here end up in the same place.
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn default_root() -> Option<PathBuf> {
+        Some(dirs::cache_dir()?.join("advent_of_code"))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.year_dir(year).join(format!("input_day_{day}"))
+    }
+
+    fn year_dir(&self, year: u32) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> io::Result<String> {
+        fs::read_to_string(self.path(year, day))
+    }
+
+    // Normalizes and stores an input, recording its hash for later verification
+    pub fn insert(&self, year: u32, day: u32, input: &str) -> io::Result<u64> {
+        let input = normalize(input);
+        let hash = content_hash(&input);
+        let mut checksums = self.checksums(year)?;
+
+        fs::create_dir_all(self.year_dir(year))?;
+        fs::write(self.path(year, day), input.as_bytes())?;
+        checksums.insert(day, hash);
+        self.save_checksums(year, &checksums)?;
+
+        Ok(hash)
+    }
+
+    // Adopts an input that is already in place, e.g. one downloaded by `aoc`
+    pub fn record(&self, year: u32, day: u32) -> io::Result<u64> {
+        let input = self.read(year, day)?;
+
+        self.insert(year, day, &input)
+    }
+
+    pub fn status(&self, year: u32, day: u32) -> io::Result<Status> {
+        let input = match self.read(year, day) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Status::Missing),
+            Err(err) => return Err(err),
+        };
+
+        let Some(&recorded) = self.checksums(year)?.get(&day) else {
+            if let Cow::Owned(_) = normalize(&input) {
+                return Ok(Status::Unnormalized);
+            }
+
+            return Ok(Status::Unrecorded);
+        };
+
+        let actual = content_hash(&input);
+
+        if actual != recorded {
+            return Ok(Status::Altered { recorded, actual });
+        }
+
+        Ok(Status::Verified)
+    }
+
+    pub fn year_status(&self, year: u32) -> io::Result<Vec<(u32, Status)>> {
+        DAYS.map(|day| Ok((day, self.status(year, day)?))).collect()
+    }
+
+    pub fn missing(&self, year: u32) -> io::Result<Vec<u32>> {
+        Ok(self
+            .year_status(year)?
+            .into_iter()
+            .filter(|(_, status)| *status == Status::Missing)
+            .map(|(day, _)| day)
+            .collect())
+    }
+
+    // Imports every file under `dir` whose name identifies a day, see `day_from_path`
+    pub fn import_dir(&self, year: u32, dir: &Path) -> io::Result<Vec<u32>> {
+        let mut imported = Vec::new();
+
+        for path in files(dir)? {
+            let Some(day) = day_from_path(&path) else {
+                continue;
+            };
+
+            self.insert(year, day, &fs::read_to_string(&path)?)?;
+            imported.push(day);
+        }
+
+        imported.sort_unstable();
+
+        Ok(imported)
+    }
+
+    // Accepts plain and gzip compressed tarballs
+    pub fn import_tarball(&self, year: u32, path: &Path) -> io::Result<Vec<u32>> {
+        let mut file = BufReader::new(File::open(path)?);
+        let mut magic = [0; 2];
+        let is_gzip = file.read_exact(&mut magic).is_ok() && magic == [0x1f, 0x8b];
+
+        file.rewind()?;
+
+        let archive: Box<dyn Read> = if is_gzip {
+            Box::new(GzDecoder::new(file))
+        } else {
+            Box::new(file)
+        };
+
+        let mut archive = tar::Archive::new(archive);
+        let mut imported = Vec::new();
+
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let Some(day) = day_from_path(&entry.path()?) else {
+                continue;
+            };
+
+            let mut input = String::new();
+
+            entry.read_to_string(&mut input)?;
+            self.insert(year, day, &input)?;
+            imported.push(day);
+        }
+
+        imported.sort_unstable();
+
+        Ok(imported)
+    }
+
+    fn checksums(&self, year: u32) -> io::Result<BTreeMap<u32, u64>> {
+        let checksums = match fs::read_to_string(self.year_dir(year).join(CHECKSUMS)) {
+            Ok(checksums) => checksums,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(err) => return Err(err),
+        };
+
+        checksums
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                parse_checksum(line).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid checksum line: {line:?}"),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn save_checksums(&self, year: u32, checksums: &BTreeMap<u32, u64>) -> io::Result<()> {
+        let contents = checksums
+            .iter()
+            .map(|(day, hash)| format!("input_day_{day} {hash:016x}\n"))
+            .collect::<String>();
+
+        fs::write(self.year_dir(year).join(CHECKSUMS), contents)
+    }
+}
+
+fn parse_checksum(line: &str) -> Option<(u32, u64)> {
+    let (name, hash) = line.split_once(' ')?;
+    let day = name.strip_prefix("input_day_")?.parse().ok()?;
+    let hash = u64::from_str_radix(hash.trim(), 16).ok()?;
+
+    Some((day, hash))
+}
+
+// Inputs from the site use `\n` and end with a newline. Editors and copy-paste
+// tend to break both, which trips up parsers that expect exactly that shape.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    if !input.contains('\r') && (input.is_empty() || input.ends_with('\n')) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    if !normalized.is_empty() && !normalized.ends_with('\n') {
+        normalized.push('\n');
+    }
+
+    Cow::Owned(normalized)
+}
+
+// FNV-1a; this guards against accidental edits, not tampering
+pub fn content_hash(input: &str) -> u64 {
+    let mut hasher = FnvHasher::default();
+
+    hasher.write(input.as_bytes());
+    hasher.finish()
+}
+
+// Recognizes `input_day_5` (the cache layout), `day05.txt`, `day5`, `05.txt`,
+// and `input.txt` inside a `day05` directory (the layout next to each crate).
+pub fn day_from_path(path: &Path) -> Option<u32> {
+    let mut name = path.file_stem()?.to_str()?;
+
+    if name == "input" {
+        name = path.parent()?.file_name()?.to_str()?;
+    }
+
+    let digits = name
+        .trim_start_matches("input")
+        .trim_start_matches(['_', '-'])
+        .trim_start_matches("day")
+        .trim_start_matches(['_', '-']);
+
+    digits.parse().ok().filter(|day| DAYS.contains(day))
+}
+
+fn files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            files.extend(self::files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::*;
+
+    // A store in a fresh directory, removed again by `cleanup`
+    fn temp_store(name: &str) -> InputStore {
+        let root = env::temp_dir().join(format!("inputs-{name}-{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&root);
+
+        InputStore::new(root)
+    }
+
+    fn cleanup(store: InputStore) {
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn normalizes_line_endings_and_final_newline() {
+        assert!(matches!(
+            normalize("1 2\n3 4\n"),
+            Cow::Borrowed("1 2\n3 4\n")
+        ));
+        assert!(matches!(normalize(""), Cow::Borrowed("")));
+        assert_eq!(normalize("1 2\r\n3 4\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\r3 4"), "1 2\n3 4\n");
+        assert_eq!(normalize("2333133121414131402"), "2333133121414131402\n");
+    }
+
+    #[test]
+    fn recognizes_days_in_paths() {
+        let day = |path: &str| day_from_path(Path::new(path));
+
+        assert_eq!(day("cache/2024/input_day_5"), Some(5));
+        assert_eq!(day("inputs/day05.txt"), Some(5));
+        assert_eq!(day("day5"), Some(5));
+        assert_eq!(day("05.txt"), Some(5));
+        assert_eq!(day("day-25.txt"), Some(25));
+        assert_eq!(day("crate/day12_2/input.txt"), None);
+        assert_eq!(day("crate/day12/input.txt"), Some(12));
+        assert_eq!(day("day26.txt"), None);
+        assert_eq!(day("day00.txt"), None);
+        assert_eq!(day("notes.txt"), None);
+    }
+
+    #[test]
+    fn checksums_survive_a_round_trip() {
+        let store = temp_store("checksums");
+        let hash = store.insert(2024, 3, "mul(2,4)\r\n").unwrap();
+
+        assert_eq!(hash, content_hash("mul(2,4)\n"));
+        assert_eq!(store.read(2024, 3).unwrap(), "mul(2,4)\n");
+        assert_eq!(
+            fs::read_to_string(store.root().join("2024").join(CHECKSUMS)).unwrap(),
+            format!("input_day_3 {hash:016x}\n")
+        );
+
+        let store = InputStore::new(store.root());
+
+        assert_eq!(store.checksums(2024).unwrap(), BTreeMap::from([(3, hash)]));
+        assert_eq!(store.status(2024, 3).unwrap(), Status::Verified);
+        assert_eq!(store.status(2024, 4).unwrap(), Status::Missing);
+        assert_eq!(store.missing(2024).unwrap().len(), 24);
+
+        fs::write(store.path(2024, 3), "mul(2,5)\n").unwrap();
+
+        assert_eq!(
+            store.status(2024, 3).unwrap(),
+            Status::Altered {
+                recorded: hash,
+                actual: content_hash("mul(2,5)\n")
+            }
+        );
+
+        cleanup(store);
+    }
+
+    #[test]
+    fn reports_unrecorded_inputs() {
+        let store = temp_store("unrecorded");
+
+        fs::create_dir_all(store.root().join("2024")).unwrap();
+        fs::write(store.path(2024, 1), "3   4\n").unwrap();
+        fs::write(store.path(2024, 2), "7 6 4\r\n").unwrap();
+
+        assert_eq!(store.status(2024, 1).unwrap(), Status::Unrecorded);
+        assert_eq!(store.status(2024, 2).unwrap(), Status::Unnormalized);
+
+        store.record(2024, 2).unwrap();
+
+        assert_eq!(store.read(2024, 2).unwrap(), "7 6 4\n");
+        assert_eq!(store.status(2024, 2).unwrap(), Status::Verified);
+
+        cleanup(store);
+    }
+
+    #[test]
+    fn imports_gzipped_tarballs() {
+        let store = temp_store("tarball");
+        let tarball = store.root().join("inputs.tar.gz");
+        let mut builder = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+
+        for (path, contents) in [
+            ("inputs/day01.txt", "3   4\r\n4   3"),
+            ("inputs/day02/input.txt", "7 6 4 2 1\n"),
+            ("inputs/README", "not an input\n"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, contents.as_bytes())
+                .unwrap();
+        }
+
+        fs::create_dir_all(store.root()).unwrap();
+        fs::write(&tarball, builder.into_inner().unwrap().finish().unwrap()).unwrap();
+
+        assert_eq!(store.import_tarball(2024, &tarball).unwrap(), [1, 2]);
+        assert_eq!(store.read(2024, 1).unwrap(), "3   4\n4   3\n");
+        assert_eq!(store.status(2024, 1).unwrap(), Status::Verified);
+        assert_eq!(store.status(2024, 2).unwrap(), Status::Verified);
+        assert_eq!(store.status(2024, 3).unwrap(), Status::Missing);
+
+        cleanup(store);
+    }
+}
@@ -0,0 +1,288 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+mod puzzle;
+use puzzle::extract_examples;
+
+mod stub;
+use stub::non_stub_sources;
+
+mod templates;
+use templates::{crate_dir, render, Day, File, WORKSPACE_TOML};
+
+const USAGE: &str = "\
+usage:
+    scaffold day <year> <day> [--root <dir>]
+    scaffold year <year> [--root <dir>]
+    scaffold examples <day> <saved puzzle page> [--root <dir>]
+
+Generates the crates for both parts of a day, or for every day of a year.
+Crates that already contain solution code are never overwritten, and
+existing manifests are kept. A new workspace gets a copy of `utils`.
+Parts return `utils::solution::Answer`, which any integer or string
+converts into, so there is no `--returns <type>` option anymore.
+
+`examples` writes the examples and answers from a puzzle page saved in the
+browser to the day's `fixtures/`, where the generated tests pick them up.
+Examples whose answers can't be told apart from others are left out.";
+
+struct Options {
+    command: Command,
+    root: PathBuf,
+}
+
+enum Command {
+    Day { year: u32, day: u32 },
+    Year { year: u32 },
+    Examples { day: u32, page: PathBuf },
+}
+
+fn main() -> ExitCode {
+    let Some(options) = parse_args(env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    match run(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Option<Options> {
+    let mut positional = Vec::new();
+    let mut root = PathBuf::from(".");
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = args.next()?.into(),
+            _ if arg.starts_with("--") => return None,
+            _ => positional.push(arg),
+        }
+    }
+
+    let command = match positional.as_slice() {
+        [command, year, day] if command == "day" => Command::Day {
+            year: year.parse().ok()?,
+            day: day.parse().ok().filter(|day| (1..=25).contains(day))?,
+        },
+        [command, year] if command == "year" => Command::Year {
+            year: year.parse().ok()?,
+        },
+        [command, day, page] if command == "examples" => Command::Examples {
+            day: day.parse().ok().filter(|day| (1..=25).contains(day))?,
+            page: page.into(),
+        },
+        _ => return None,
+    };
+
+    Some(Options { command, root })
+}
+
+fn run(options: Options) -> io::Result<()> {
+    let days = match options.command {
+        Command::Day { year, day } => vec![Day { year, day }],
+        Command::Year { year } => (1..=25).map(|day| Day { year, day }).collect(),
+        Command::Examples { day, page } => return write_examples(&options.root, day, &page),
+    };
+
+    ensure_only_stubs(&options.root, &days)?;
+
+    if matches!(options.command, Command::Year { .. }) {
+        init_workspace(&options.root)?;
+    }
+
+    for day in &days {
+        for file in day.files() {
+            write_file(&options.root, &file)?;
+        }
+
+        let (manifest, dependency) = day.part2_manifest();
+
+        add_dependency(&options.root, &manifest, &dependency)?;
+    }
+
+    Ok(())
+}
+
+// Day crates depend on `../utils`, so a new workspace gets a copy of the one next to this tool
+fn init_workspace(root: &Path) -> io::Result<()> {
+    let manifest = root.join("Cargo.toml");
+
+    if manifest.exists() {
+        return Ok(());
+    }
+
+    if !root.join("utils").exists() {
+        copy_dir(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../utils"),
+            &root.join("utils"),
+        )?;
+        println!("created  utils");
+    }
+
+    let members = ["day*", "scaffold", "utils"]
+        .iter()
+        .filter(|member| member.contains('*') || root.join(member).is_dir())
+        .map(|member| format!("{member:?}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    write_if_missing(&manifest, &render(WORKSPACE_TOML, &[("members", &members)]))
+}
+
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap();
+
+        if name == "target" {
+            continue;
+        }
+
+        if path.is_dir() {
+            copy_dir(&path, &to.join(name))?;
+        } else {
+            fs::copy(&path, to.join(name))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Kept manifests of part 2 crates from before the solution module still need to depend on it
+fn add_dependency(root: &Path, manifest: &Path, dependency: &str) -> io::Result<()> {
+    let path = root.join(manifest);
+    let contents = fs::read_to_string(&path)?;
+    let package = dependency.split(' ').next().unwrap();
+
+    if contents
+        .lines()
+        .any(|line| line.split(' ').next() == Some(package))
+    {
+        return Ok(());
+    }
+
+    let contents = match contents.split_once("[dependencies]\n") {
+        // Sorted in, like `cargo add` does
+        Some((before, after)) => {
+            let position = after
+                .lines()
+                .take_while(|line| !line.is_empty() && !line.starts_with('[') && *line < dependency)
+                .map(|line| line.len() + 1)
+                .sum::<usize>();
+            let (earlier, later) = after.split_at(position);
+
+            format!("{before}[dependencies]\n{earlier}{dependency}{later}")
+        }
+        None => format!("{}\n[dependencies]\n{dependency}", contents.trim_end()),
+    };
+
+    fs::write(&path, contents)?;
+    println!("updated  {}", manifest.display());
+
+    Ok(())
+}
+
+fn write_examples(root: &Path, day: u32, page: &Path) -> io::Result<()> {
+    let fixtures = extract_examples(&fs::read_to_string(page)?);
+
+    if fixtures.is_empty() {
+        return Err(io::Error::other(format!(
+            "no examples with answers found in {}",
+            page.display()
+        )));
+    }
+
+    for fixture in fixtures {
+        let file = File {
+            path: Path::new(&crate_dir(day, 1))
+                .join("fixtures")
+                .join(format!("{}.txt", fixture.name)),
+            contents: fixture.to_string(),
+            keep_existing: false,
+            replaces: Vec::new(),
+        };
+
+        write_file(root, &file)?;
+    }
+
+    Ok(())
+}
+
+// Checks every crate up front so that a refusal leaves the tree untouched
+fn ensure_only_stubs(root: &Path, days: &[Day]) -> io::Result<()> {
+    let mut non_stubs = Vec::new();
+
+    for day in days {
+        for crate_dir in day.crate_dirs() {
+            non_stubs.extend(non_stub_sources(&root.join(crate_dir))?);
+        }
+    }
+
+    if non_stubs.is_empty() {
+        return Ok(());
+    }
+
+    let files = non_stubs
+        .iter()
+        .map(|path| format!("    {}", path.display()))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Err(io::Error::other(format!(
+        "refusing to overwrite crates containing solution code:\n{files}"
+    )))
+}
+
+fn write_file(root: &Path, file: &File) -> io::Result<()> {
+    let path = root.join(&file.path);
+
+    let existing = fs::read_to_string(&path).ok();
+
+    if file.keep_existing
+        && path.exists()
+        && !existing
+            .as_ref()
+            .is_some_and(|contents| file.replaces.contains(contents))
+    {
+        println!("kept     {}", file.path.display());
+        return Ok(());
+    }
+
+    if existing.is_some_and(|contents| contents == file.contents) {
+        println!("same     {}", file.path.display());
+        return Ok(());
+    }
+
+    let status = if path.exists() { "updated" } else { "created" };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(&path, &file.contents)?;
+    println!("{status:<8} {}", file.path.display());
+
+    Ok(())
+}
+
+fn write_if_missing(path: &Path, contents: &str) -> io::Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    fs::write(path, contents)?;
+    println!("created  {}", path.display());
+
+    Ok(())
+}
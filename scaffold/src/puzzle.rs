@@ -0,0 +1,187 @@
+use select::document::Document;
+use select::node::Node;
+use select::predicate::{Class, Name, Predicate};
+use utils::fixture::Fixture;
+
+// Each part of a saved puzzle page is an `<article class="day-desc">`, with examples in
+// `<pre><code>` blocks and answers emphasized like `<code><em>11</em></code>`.
+//
+// An answer belongs to the block before it, but only if that is the one block since the
+// previous answer. Pages also show intermediate states in `<pre>` blocks (like the disk
+// maps of 2024 day 9), and after several of them there's no telling which is the input.
+// A block that starts like an earlier, different one is such a state and no input either.
+// Answers in a paragraph ending with a colon introduce the block after it instead.
+// Part two may reuse the example of part one without repeating it, which is only taken
+// for granted if part one has a single example. Examples without answers are left out.
+pub fn extract_examples(html: &str) -> Vec<Fixture> {
+    let document = Document::from(html);
+    let mut fixtures = Vec::<Fixture>::new();
+    let mut seen = Vec::<String>::new();
+
+    for (part, article) in document
+        .find(Name("article").and(Class("day-desc")))
+        .take(2)
+        .enumerate()
+    {
+        // Blocks since the previous answer, `None` for intermediate states
+        let mut blocks = Vec::new();
+        let mut has_blocks = false;
+        let mut introduced = None;
+
+        for node in article.descendants() {
+            if node.name() == Some("pre") {
+                let block = node.text();
+                let is_state = seen.iter().any(|earlier: &String| {
+                    *earlier != block && first_line(earlier) == first_line(&block)
+                });
+
+                if !seen.contains(&block) {
+                    seen.push(block.clone());
+                }
+
+                if introduced != Some(node.index()) {
+                    blocks.push((!is_state).then_some(block));
+                }
+
+                has_blocks = true;
+                continue;
+            }
+
+            let Some(answer) = answer(&node) else {
+                continue;
+            };
+
+            let next_block = introduced_block(&node);
+            let example = match (&next_block, blocks.as_slice()) {
+                (Some(pre), _) => Some(pre.text()),
+                (None, [Some(block)]) => Some(block.clone()),
+                // Before any block of part two, `seen` holds those of part one
+                (None, []) if part == 1 && !has_blocks && seen.len() == 1 => seen.first().cloned(),
+                _ => None,
+            };
+
+            introduced = next_block.map(|pre| pre.index());
+
+            if let Some(input) = example {
+                let fixture = fixture_for(&mut fixtures, &input);
+
+                match part {
+                    0 => fixture.part1 = Some(answer),
+                    _ => fixture.part2 = Some(answer),
+                }
+            }
+
+            blocks.clear();
+        }
+    }
+
+    fixtures
+}
+
+fn fixture_for<'a>(fixtures: &'a mut Vec<Fixture>, input: &str) -> &'a mut Fixture {
+    let index = match fixtures.iter().position(|fixture| fixture.input == input) {
+        Some(index) => index,
+        None => {
+            let name = format!("example_{}", fixtures.len() + 1);
+
+            fixtures.push(Fixture::new(name, input));
+            fixtures.len() - 1
+        }
+    };
+
+    &mut fixtures[index]
+}
+
+fn first_line(block: &str) -> &str {
+    block.lines().next().unwrap_or("")
+}
+
+fn answer(node: &Node) -> Option<String> {
+    let is_emphasized_code = node.name() == Some("em")
+        && (node
+            .parent()
+            .is_some_and(|parent| parent.name() == Some("code"))
+            || node.children().any(|child| child.name() == Some("code")));
+
+    (is_emphasized_code && !inside_pre(node)).then(|| node.text().trim().to_string())
+}
+
+// The `<pre>` right after the paragraph of `node`, if that paragraph ends with a colon
+fn introduced_block<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut paragraph = node.parent()?;
+
+    while paragraph.name() != Some("p") {
+        paragraph = paragraph.parent()?;
+    }
+
+    if !paragraph.text().trim_end().ends_with(':') {
+        return None;
+    }
+
+    let mut next = paragraph.next();
+
+    while let Some(sibling) = next.filter(|sibling| sibling.name().is_none()) {
+        next = sibling.next();
+    }
+
+    next.filter(|sibling| sibling.name() == Some("pre"))
+}
+
+// Emphasis inside examples highlights parts of the input, not answers
+fn inside_pre(node: &Node) -> bool {
+    let mut node = node.parent();
+
+    while let Some(parent) = node {
+        if parent.name() == Some("pre") {
+            return true;
+        }
+
+        node = parent.parent();
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Headers and inputs of the fixtures extracted from a page in `tests/pages`
+    fn extract(page: &str) -> Vec<String> {
+        extract_examples(page)
+            .iter()
+            .map(|fixture| format!("{}:\n{fixture}", fixture.name))
+            .collect()
+    }
+
+    #[test]
+    fn part_two_reuses_the_only_example() {
+        assert_eq!(
+            extract(include_str!("../tests/pages/day01.html")),
+            ["example_1:\npart1: 11\npart2: 31\n---\n3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n"]
+        );
+        assert_eq!(
+            extract(include_str!("../tests/pages/day02.html")),
+            ["example_1:\npart1: 2\npart2: 4\n---\n7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n"]
+        );
+    }
+
+    #[test]
+    fn answers_belong_to_the_example_before_them() {
+        assert_eq!(
+            extract(include_str!("../tests/pages/day12.html")),
+            [
+                "example_1:\npart1: 140\n---\nAAAA\nBBCD\nBBCC\nEEEC\n",
+                "example_2:\npart1: 772\n---\nOOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n",
+                "example_3:\npart1: 1930\n---\nRRRRIICCFF\nRRRRIICCCF\nVVRRRCCFFF\n",
+                "example_4:\npart2: 236\n---\nEEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE\n",
+                "example_5:\npart2: 368\n---\nAAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn answers_after_several_blocks_are_left_out() {
+        assert!(extract(include_str!("../tests/pages/day09.html")).is_empty());
+    }
+}
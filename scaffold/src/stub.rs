@@ -0,0 +1,157 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Matches code that only declares things, passes the input through or bails out
+// with `unimplemented!()`, like the files generated by this tool and by the old `setup.nu`.
+pub fn is_stub(source: &str) -> bool {
+    source.lines().map(str::trim).all(is_stub_line)
+}
+
+fn is_stub_line(line: &str) -> bool {
+    let is_declaration = ["use ", "mod ", "pub mod ", "pub struct ", "type "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+        && line.ends_with(';');
+    let is_signature = ["fn ", "pub fn ", "impl "]
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+        && line.ends_with('{');
+
+    line.is_empty()
+        || line.starts_with("//")
+        || line.starts_with("#[")
+        || line == "}"
+        || line == "unimplemented!()"
+        || line == "todo!()"
+        || line == "input"
+        || is_declaration
+        || is_signature
+        || is_delegation(line)
+}
+
+// `DayN::solve(input, part)`, or `dayN::solution::partN(input)` from earlier versions
+fn is_delegation(line: &str) -> bool {
+    let solves = line
+        .split_once("::solve(input, ")
+        .is_some_and(|(_, part)| part == "1)" || part == "2)");
+    let calls_part = line
+        .strip_suffix("(input)")
+        .and_then(|path| path.rsplit("::").next())
+        .is_some_and(|function| function == "part1" || function == "part2");
+
+    solves || calls_part
+}
+
+// Source files of an existing crate that contain actual solution code
+pub fn non_stub_sources(crate_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut non_stubs = Vec::new();
+
+    for path in rust_files(&crate_dir.join("src"))? {
+        if !is_stub(&fs::read_to_string(&path)?) {
+            non_stubs.push(path);
+        }
+    }
+
+    Ok(non_stubs)
+}
+
+fn rust_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+
+    if !dir.is_dir() {
+        return Ok(files);
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            files.extend(rust_files(&path)?);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::Day;
+
+    const SOLUTION: &str = "\
+use utils::solution::{Answer, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<u32>;
+
+    fn parse(input: &str) -> Vec<u32> {
+        input.lines().map(|line| line.parse().unwrap()).collect()
+    }
+
+    fn part1(input: &Vec<u32>) -> Answer {
+        input.iter().sum::<u32>().into()
+    }
+}
+";
+
+    fn generated_sources() -> Vec<String> {
+        Day {
+            year: 2024,
+            day: 16,
+        }
+        .files()
+        .into_iter()
+        .filter(|file| file.path.starts_with("day16/src") || file.path.starts_with("day16_2/src"))
+        .map(|file| file.contents)
+        .collect()
+    }
+
+    #[test]
+    fn generated_files_are_stubs() {
+        let sources = generated_sources();
+
+        assert_eq!(sources.len(), 4);
+        assert!(sources.iter().all(|source| is_stub(source)));
+    }
+
+    #[test]
+    fn stubs_of_earlier_versions_are_stubs() {
+        assert!(is_stub(
+            "use aoc::aoc;\n\n#[aoc(2024, 16, 1)]\nfn main(input: &str) -> i32 {\n    unimplemented!()\n}"
+        ));
+        assert!(is_stub(
+            "use aoc::aoc;\n\n#[aoc(2024, 16, 2)]\nfn main(input: &str) -> i64 {\n    day16::solution::part2(input)\n}\n"
+        ));
+        assert!(is_stub(
+            "pub fn part1(input: &str) -> i64 {\n    todo!()\n}\n"
+        ));
+    }
+
+    #[test]
+    fn whitespace_changes_keep_stubs() {
+        for source in generated_sources() {
+            let reindented = source
+                .lines()
+                .map(|line| format!("\t{}  ", line.trim()))
+                .collect::<Vec<_>>()
+                .join("\r\n\r\n");
+
+            assert!(is_stub(&reindented), "{reindented}");
+        }
+    }
+
+    #[test]
+    fn solutions_are_not_stubs() {
+        assert!(!is_stub(SOLUTION));
+        assert!(!is_stub(
+            "use aoc::aoc;\n\n#[aoc(2024, 16, 1)]\nfn main(input: &str) -> i32 {\n    input.len() as i32\n}"
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+use std::path::PathBuf;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const SOLUTION_RS: &str = include_str!("../templates/solution.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const EXAMPLES_RS: &str = include_str!("../templates/examples.rs.tmpl");
+// Example tests generated by earlier versions, which call `part1` and `part2` functions
+// instead of `DayN`. Unedited copies are replaced, edited ones are kept.
+const LEGACY_EXAMPLES_RS: [&str; 2] = [
+    include_str!("../templates/legacy/examples_consts.rs.tmpl"),
+    include_str!("../templates/legacy/examples_parts.rs.tmpl"),
+];
+pub const WORKSPACE_TOML: &str = include_str!("../templates/workspace.toml.tmpl");
+
+pub struct File {
+    pub path: PathBuf,
+    pub contents: String,
+    // Files the user is expected to edit, e.g. pasted examples
+    pub keep_existing: bool,
+    // Earlier generated contents that are overwritten even when keeping existing files
+    pub replaces: Vec<String>,
+}
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl Day {
+    // Part 1 holds the shared solution module, part 2 depends on it
+    pub fn crate_dirs(&self) -> [String; 2] {
+        [1, 2].map(|part| self.crate_dir(part))
+    }
+
+    fn crate_dir(&self, part: u32) -> String {
+        crate_dir(self.day, part)
+    }
+
+    fn package(&self, part: u32) -> String {
+        match part {
+            1 => format!("day{}", self.day),
+            _ => format!("day{}_{part}", self.day),
+        }
+    }
+
+    pub fn files(&self) -> Vec<File> {
+        let part1_dir = PathBuf::from(self.crate_dir(1));
+        let part2_dir = PathBuf::from(self.crate_dir(2));
+        let lib_dependency = self.lib_dependency();
+
+        vec![
+            self.manifest(part1_dir.join("Cargo.toml"), 1, ""),
+            self.file(part1_dir.join("src/lib.rs"), LIB_RS, 1, ""),
+            self.file(part1_dir.join("src/solution.rs"), SOLUTION_RS, 1, ""),
+            self.file(part1_dir.join("src/main.rs"), MAIN_RS, 1, ""),
+            File {
+                keep_existing: true,
+                replaces: LEGACY_EXAMPLES_RS
+                    .iter()
+                    .map(|template| self.render(template, 1, ""))
+                    .collect(),
+                ..self.file(part1_dir.join("tests/examples.rs"), EXAMPLES_RS, 1, "")
+            },
+            self.manifest(part2_dir.join("Cargo.toml"), 2, &lib_dependency),
+            self.file(part2_dir.join("src/main.rs"), MAIN_RS, 2, ""),
+        ]
+    }
+
+    // Part 2 depends on part 1 for the solution module
+    pub fn part2_manifest(&self) -> (PathBuf, String) {
+        let path = PathBuf::from(self.crate_dir(2)).join("Cargo.toml");
+
+        (path, self.lib_dependency())
+    }
+
+    fn lib_dependency(&self) -> String {
+        format!(
+            "{} = {{ version = \"0.1.0\", path = \"../{}\" }}\n",
+            self.package(1),
+            self.crate_dir(1)
+        )
+    }
+
+    // Existing manifests may list dependencies added by hand, so they are kept
+    fn manifest(&self, path: PathBuf, part: u32, dependencies: &str) -> File {
+        File {
+            keep_existing: true,
+            ..self.file(path, CARGO_TOML, part, dependencies)
+        }
+    }
+
+    fn file(&self, path: PathBuf, template: &str, part: u32, dependencies: &str) -> File {
+        File {
+            path,
+            contents: self.render(template, part, dependencies),
+            keep_existing: false,
+            replaces: Vec::new(),
+        }
+    }
+
+    fn render(&self, template: &str, part: u32, dependencies: &str) -> String {
+        render(
+            template,
+            &[
+                ("package", &self.package(part)),
+                ("lib", &self.package(1)),
+                ("dependencies", dependencies),
+                ("year", &self.year.to_string()),
+                ("day", &self.day.to_string()),
+                ("part", &part.to_string()),
+            ],
+        )
+    }
+}
+
+pub fn crate_dir(day: u32, part: u32) -> String {
+    match part {
+        1 => format!("day{day:02}"),
+        _ => format!("day{day:02}_{part}"),
+    }
+}
+
+pub fn render(template: &str, vars: &[(&str, &str)]) -> String {
+    vars.iter()
+        .fold(template.to_string(), |rendered, (name, value)| {
+            rendered.replace(&format!("{{{{{name}}}}}"), value)
+        })
+}
@@ -0,0 +1,98 @@
+use std::io;
+
+use reqwest::header::COOKIE;
+use select::document::Document;
+use select::predicate::Name;
+
+use crate::log::Verdict;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Verdict(Verdict),
+    RateLimited,
+    // The part is locked or was already solved
+    WrongLevel,
+    Unknown,
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    http: reqwest::blocking::Client,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            session: session.into(),
+            http: reqwest::blocking::Client::new(),
+        }
+    }
+
+    // Returns the classified response along with the message shown on the page
+    pub fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> io::Result<(Response, String)> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        let body = self
+            .http
+            .post(url)
+            .header(COOKIE, format!("session={}", self.session))
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .map_err(io::Error::other)?;
+
+        let message = message(&body);
+
+        Ok((classify(&message), message))
+    }
+}
+
+fn message(body: &str) -> String {
+    let document = Document::from(body);
+    let text = match document.find(Name("main")).next() {
+        Some(main) => main.text(),
+        None => document
+            .find(Name("body"))
+            .next()
+            .map_or_else(String::new, |body| body.text()),
+    };
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn classify(message: &str) -> Response {
+    if message.contains("That's the right answer") {
+        return Response::Verdict(Verdict::Correct);
+    }
+
+    if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            return Response::Verdict(Verdict::TooHigh);
+        }
+
+        if message.contains("too low") {
+            return Response::Verdict(Verdict::TooLow);
+        }
+
+        return Response::Verdict(Verdict::Wrong);
+    }
+
+    if message.contains("You gave an answer too recently") {
+        return Response::RateLimited;
+    }
+
+    if message.contains("You don't seem to be solving the right level") {
+        return Response::WrongLevel;
+    }
+
+    Response::Unknown
+}
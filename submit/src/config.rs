@@ -0,0 +1,85 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// `key = value` lines, `#` starts a comment:
+//
+// ```text
+// session = 53616c7465645f5f...
+// base_url = http://localhost:8000
+// log_dir = /home/me/aoc-answers
+// ```
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub log_dir: PathBuf,
+}
+
+impl Config {
+    pub fn default_path() -> Option<PathBuf> {
+        Some(
+            dirs::config_dir()?
+                .join("advent_of_code")
+                .join("submit.conf"),
+        )
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut config = Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            log_dir: dirs::data_dir().unwrap_or_default().join("advent_of_code"),
+        };
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(config),
+            Err(err) => return Err(err),
+        };
+
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(invalid(path, line));
+            };
+
+            let value = value.trim().to_string();
+
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "log_dir" => config.log_dir = value.into(),
+                _ => return Err(invalid(path, line)),
+            }
+        }
+
+        Ok(config)
+    }
+
+    // Falls back to the token set up through the `aoc` crate's `config` subcommand
+    pub fn session(&self) -> Option<String> {
+        self.session
+            .clone()
+            .or_else(|| aoc::config::session_token().ok())
+            .filter(|session| !session.is_empty())
+    }
+
+    pub fn log_path(&self, year: u32) -> PathBuf {
+        self.log_dir.join(year.to_string()).join("answers")
+    }
+}
+
+fn invalid(path: &Path, line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("{}: invalid line {line:?}", path.display()),
+    )
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = ();
+
+    fn from_str(verdict: &str) -> Result<Self, ()> {
+        Ok(match verdict {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u32,
+    pub part: u32,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+// Reasons to not even send an answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadyAccepted,
+    AlreadySolved { correct: String },
+    KnownWrong { verdict: Verdict },
+    // An earlier answer at or below this one was already too high
+    TooHigh { bound: String },
+    TooLow { bound: String },
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::AlreadyAccepted => write!(f, "this answer was already accepted"),
+            Rejection::AlreadySolved { correct } => {
+                write!(f, "already solved, the correct answer was {correct}")
+            }
+            Rejection::KnownWrong { verdict } => {
+                write!(f, "this answer was already submitted and was {verdict}")
+            }
+            Rejection::TooHigh { bound } => write!(f, "too high, {bound} was already too high"),
+            Rejection::TooLow { bound } => write!(f, "too low, {bound} was already too low"),
+        }
+    }
+}
+
+// One line per submission, `<day> <part> <verdict> <answer>`, appended as answers come back
+pub struct AnswerLog {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl AnswerLog {
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                parse_entry(line).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: invalid entry {line:?}", path.display()),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self, day: u32, part: u32) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.day == day && entry.part == part)
+    }
+
+    pub fn all_entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn correct_answer(&self, day: u32, part: u32) -> Option<&str> {
+        self.entries(day, part)
+            .find(|entry| entry.verdict == Verdict::Correct)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Result<(), Rejection> {
+        if let Some(correct) = self.correct_answer(day, part) {
+            if correct == answer {
+                return Err(Rejection::AlreadyAccepted);
+            }
+
+            return Err(Rejection::AlreadySolved {
+                correct: correct.to_string(),
+            });
+        }
+
+        if let Some(entry) = self.entries(day, part).find(|entry| entry.answer == answer) {
+            return Err(Rejection::KnownWrong {
+                verdict: entry.verdict,
+            });
+        }
+
+        // Bounds only apply to numbers; answers like `1,2,3` are only checked for equality
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict| {
+            self.entries(day, part)
+                .filter(move |entry| entry.verdict == verdict)
+                .filter_map(|entry| Some((entry.answer.parse::<i128>().ok()?, &entry.answer)))
+        };
+
+        if let Some((_, bound)) = bound(Verdict::TooHigh)
+            .filter(|(high, _)| number >= *high)
+            .min()
+        {
+            return Err(Rejection::TooHigh {
+                bound: bound.clone(),
+            });
+        }
+
+        if let Some((_, bound)) = bound(Verdict::TooLow)
+            .filter(|(low, _)| number <= *low)
+            .max()
+        {
+            return Err(Rejection::TooLow {
+                bound: bound.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, entry: Entry) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(
+            file,
+            "{} {} {} {}",
+            entry.day, entry.part, entry.verdict, entry.answer
+        )?;
+        self.entries.push(entry);
+
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.splitn(4, ' ');
+
+    Some(Entry {
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: fields.next()?.parse().ok()?,
+        answer: fields.next()?.to_string(),
+    })
+}
@@ -0,0 +1,163 @@
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use submit::client::{Client, Response};
+use submit::config::Config;
+use submit::log::{AnswerLog, Verdict};
+use submit::submission::{self, Outcome};
+
+const USAGE: &str = "\
+usage:
+    submit <year> <day> <part> <answer> [--config <file>]
+    submit check <year> <day> <part> <answer> [--config <file>]
+    submit history <year> [<day>] [--config <file>]
+
+`check` only consults the answer log and never contacts the server.
+
+The config file defaults to `advent_of_code/submit.conf` in the user's config
+directory and holds `session`, `base_url` and `log_dir` as `key = value` lines.
+Without a `session` the token configured for the `aoc` crate is used.";
+
+struct Options {
+    command: Command,
+    config: Option<PathBuf>,
+}
+
+enum Command {
+    Submit(Answer),
+    Check(Answer),
+    History { year: u32, day: Option<u32> },
+}
+
+struct Answer {
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: String,
+}
+
+fn main() -> ExitCode {
+    let Some(options) = parse_args(env::args().skip(1)) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let Some(config_path) = options.config.or_else(Config::default_path) else {
+        eprintln!("error: no config directory on this platform, pass --config");
+        return ExitCode::FAILURE;
+    };
+
+    let result = Config::load(&config_path).and_then(|config| run(&config, options.command));
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Option<Options> {
+    let mut positional = Vec::new();
+    let mut config = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config = Some(args.next()?.into()),
+            _ if arg.starts_with("--") => return None,
+            _ => positional.push(arg),
+        }
+    }
+
+    let command = match positional.as_slice() {
+        [command, year] if command == "history" => Command::History {
+            year: year.parse().ok()?,
+            day: None,
+        },
+        [command, year, day] if command == "history" => Command::History {
+            year: year.parse().ok()?,
+            day: Some(day.parse().ok()?),
+        },
+        [command, rest @ ..] if command == "check" => Command::Check(parse_answer(rest)?),
+        rest => Command::Submit(parse_answer(rest)?),
+    };
+
+    Some(Options { command, config })
+}
+
+fn parse_answer(args: &[String]) -> Option<Answer> {
+    let [year, day, part, answer] = args else {
+        return None;
+    };
+
+    Some(Answer {
+        year: year.parse().ok()?,
+        day: day.parse().ok().filter(|day| (1..=25).contains(day))?,
+        part: part.parse().ok().filter(|part| (1..=2).contains(part))?,
+        answer: answer.trim().to_string(),
+    })
+}
+
+fn run(config: &Config, command: Command) -> io::Result<bool> {
+    match command {
+        Command::Submit(answer) => {
+            let session = config.session().ok_or_else(|| {
+                io::Error::other("no session token, set `session` in the config file")
+            })?;
+            let client = Client::new(&config.base_url, session);
+            let mut log = AnswerLog::load(config.log_path(answer.year))?;
+            let outcome = submission::submit(
+                &mut log,
+                &client,
+                answer.year,
+                answer.day,
+                answer.part,
+                &answer.answer,
+            )?;
+
+            Ok(match outcome {
+                Outcome::Rejected(rejection) => {
+                    println!("not submitted: {rejection}");
+                    false
+                }
+                Outcome::Answered { response, message } => {
+                    println!("{message}");
+                    response == Response::Verdict(Verdict::Correct)
+                }
+            })
+        }
+        Command::Check(answer) => {
+            let log = AnswerLog::load(config.log_path(answer.year))?;
+
+            Ok(match log.check(answer.day, answer.part, &answer.answer) {
+                Ok(()) => {
+                    println!("not ruled out by earlier answers");
+                    true
+                }
+                Err(rejection) => {
+                    println!("{rejection}");
+                    false
+                }
+            })
+        }
+        Command::History { year, day } => {
+            let log = AnswerLog::load(config.log_path(year))?;
+
+            for entry in log.all_entries() {
+                if day.is_none_or(|day| day == entry.day) {
+                    println!(
+                        "day {:>2} part {}: {:<8} {}",
+                        entry.day, entry.part, entry.verdict, entry.answer
+                    );
+                }
+            }
+
+            Ok(true)
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::io;
+
+use crate::client::{Client, Response};
+use crate::log::{AnswerLog, Entry, Rejection};
+
+#[derive(Debug)]
+pub enum Outcome {
+    Rejected(Rejection),
+    Answered { response: Response, message: String },
+}
+
+// Consults the log first and records every verdict the server gives
+pub fn submit(
+    log: &mut AnswerLog,
+    client: &Client,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> io::Result<Outcome> {
+    let answer = answer.trim();
+
+    if let Err(rejection) = log.check(day, part, answer) {
+        return Ok(Outcome::Rejected(rejection));
+    }
+
+    let (response, message) = client.submit(year, day, part, answer)?;
+
+    if let Response::Verdict(verdict) = response {
+        log.record(Entry {
+            day,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        })?;
+    }
+
+    Ok(Outcome::Answered { response, message })
+}
@@ -0,0 +1,234 @@
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread::{self, JoinHandle};
+
+use submit::client::{Client, Response};
+use submit::config::Config;
+use submit::log::{AnswerLog, Rejection, Verdict};
+use submit::submission::{submit, Outcome};
+
+const RIGHT: &str =
+    "That's the right answer! You are one gold star closer to finding the Chief Historian.";
+const WRONG: &str =
+    "That's not the right answer. If you're stuck, make sure you're using the full input data.";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high. Please wait one minute before trying again.";
+const TOO_LOW: &str = "That's not the right answer; your answer is too low. Please wait one minute before trying again.";
+const TOO_RECENT: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 42s left to wait.";
+
+struct Request {
+    path: String,
+    cookie: String,
+    body: String,
+}
+
+// Answers each of `messages` to one request, wrapped in a page like the real one
+fn stub_server(messages: &[&str]) -> (String, JoinHandle<Vec<Request>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let messages = messages
+        .iter()
+        .map(|message| message.to_string())
+        .collect::<Vec<_>>();
+
+    let server = thread::spawn(move || {
+        let mut requests = Vec::new();
+
+        for message in messages {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+
+            reader.read_line(&mut line).unwrap();
+
+            let path = line.split(' ').nth(1).unwrap().to_string();
+            let mut cookie = String::new();
+            let mut len = 0;
+
+            loop {
+                line.clear();
+                reader.read_line(&mut line).unwrap();
+
+                let Some((name, value)) = line.trim_end().split_once(": ") else {
+                    break;
+                };
+
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.to_string(),
+                    "content-length" => len = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+
+            let mut body = vec![0; len];
+
+            reader.read_exact(&mut body).unwrap();
+            requests.push(Request {
+                path,
+                cookie,
+                body: String::from_utf8(body).unwrap(),
+            });
+
+            let page = format!(
+                "<html><body><header>Advent of Code</header><main><article><p>{message}</p></article></main></body></html>"
+            );
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                page.len()
+            )
+            .unwrap();
+        }
+
+        requests
+    });
+
+    (base_url, server)
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("submit-{name}-{}", std::process::id()));
+
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+#[test]
+fn classifies_responses() {
+    let (base_url, server) = stub_server(&[RIGHT, WRONG, TOO_HIGH, TOO_LOW, TOO_RECENT]);
+    let client = Client::new(base_url, "cafe");
+    let expected = [
+        Response::Verdict(Verdict::Correct),
+        Response::Verdict(Verdict::Wrong),
+        Response::Verdict(Verdict::TooHigh),
+        Response::Verdict(Verdict::TooLow),
+        Response::RateLimited,
+    ];
+
+    for (part, expected) in (1..).zip(expected) {
+        let (response, message) = client.submit(2024, 7, part, "123").unwrap();
+
+        assert_eq!(response, expected, "{message}");
+    }
+
+    let requests = server.join().unwrap();
+
+    assert_eq!(requests[0].path, "/2024/day/7/answer");
+    assert_eq!(requests[0].cookie, "session=cafe");
+    assert_eq!(requests[0].body, "level=1&answer=123");
+}
+
+#[test]
+fn records_verdicts_but_not_rate_limits() {
+    let dir = temp_dir("record");
+    let path = dir.join("answers");
+    let (base_url, server) = stub_server(&[TOO_RECENT, TOO_HIGH, RIGHT]);
+    let client = Client::new(base_url, "cafe");
+    let mut log = AnswerLog::load(&path).unwrap();
+
+    for answer in ["300", "300", "250"] {
+        submit(&mut log, &client, 2024, 1, 2, answer).unwrap();
+    }
+
+    assert_eq!(server.join().unwrap().len(), 3);
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "1 2 too-high 300\n1 2 correct 250\n"
+    );
+    assert_eq!(
+        AnswerLog::load(&path).unwrap().correct_answer(1, 2),
+        Some("250")
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn rejects_answers_outside_the_logged_bounds() {
+    let dir = temp_dir("bounds");
+    let path = dir.join("answers");
+
+    fs::write(&path, "5 1 too-high 100\n5 1 too-low 10\n5 1 wrong 50\n").unwrap();
+
+    // Nothing listens here, so any request that is sent fails the test
+    let client = Client::new("http://127.0.0.1:1", "cafe");
+    let mut log = AnswerLog::load(&path).unwrap();
+    let rejection = |log: &mut AnswerLog, answer| match submit(log, &client, 2024, 5, 1, answer) {
+        Ok(Outcome::Rejected(rejection)) => rejection,
+        outcome => panic!("{answer} was not rejected: {outcome:?}"),
+    };
+
+    let too_high = Rejection::TooHigh {
+        bound: "100".to_string(),
+    };
+    let too_low = Rejection::TooLow {
+        bound: "10".to_string(),
+    };
+
+    assert_eq!(rejection(&mut log, "101"), too_high);
+    assert_eq!(rejection(&mut log, "150"), too_high);
+    assert_eq!(rejection(&mut log, "9"), too_low);
+    assert_eq!(rejection(&mut log, "-3"), too_low);
+    assert_eq!(
+        rejection(&mut log, "100"),
+        Rejection::KnownWrong {
+            verdict: Verdict::TooHigh
+        }
+    );
+    assert_eq!(
+        rejection(&mut log, " 50\n"),
+        Rejection::KnownWrong {
+            verdict: Verdict::Wrong
+        }
+    );
+    assert_eq!(log.check(5, 1, "11"), Ok(()));
+    assert_eq!(log.check(5, 1, "99"), Ok(()));
+    assert_eq!(log.check(5, 2, "150"), Ok(()));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn config_overrides_base_url_and_log_dir() {
+    let dir = temp_dir("config");
+    let path = dir.join("submit.conf");
+    let (base_url, server) = stub_server(&[RIGHT]);
+
+    fs::write(
+        &path,
+        format!(
+            "# local stub\nsession = cafe\nbase_url = {base_url}/\nlog_dir = {}\n",
+            dir.display()
+        ),
+    )
+    .unwrap();
+
+    let config = Config::load(&path).unwrap();
+
+    assert_eq!(config.base_url, base_url);
+    assert_eq!(config.log_path(2024), dir.join("2024").join("answers"));
+
+    let client = Client::new(config.base_url.clone(), config.session().unwrap());
+    let mut log = AnswerLog::load(config.log_path(2024)).unwrap();
+    let outcome = submit(&mut log, &client, 2024, 3, 1, "161").unwrap();
+
+    assert!(matches!(
+        outcome,
+        Outcome::Answered {
+            response: Response::Verdict(Verdict::Correct),
+            ..
+        }
+    ));
+    assert_eq!(server.join().unwrap()[0].path, "/2024/day/3/answer");
+    assert_eq!(
+        fs::read_to_string(config.log_path(2024)).unwrap(),
+        "3 1 correct 161\n"
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,487 @@
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+// Grows on insert, so the capacity is only a hint
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: vec![0; bits.div_ceil(WORD_BITS)],
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.words.len() * WORD_BITS
+    }
+
+    // Returns `true` if the bit was not set before
+    pub fn insert(&mut self, bit: usize) -> bool {
+        let (word, mask) = split(bit);
+
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+
+        let was_set = self.words[word] & mask != 0;
+
+        self.words[word] |= mask;
+
+        !was_set
+    }
+
+    // Returns `true` if the bit was set before
+    pub fn remove(&mut self, bit: usize) -> bool {
+        let (word, mask) = split(bit);
+        let Some(word) = self.words.get_mut(word) else {
+            return false;
+        };
+
+        let was_set = *word & mask != 0;
+
+        *word &= !mask;
+
+        was_set
+    }
+
+    pub fn contains(&self, bit: usize) -> bool {
+        let (word, mask) = split(bit);
+
+        self.words.get(word).is_some_and(|word| word & mask != 0)
+    }
+
+    // Number of set bits
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn union_with(&mut self, other: &Self) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word |= other_word;
+        }
+    }
+
+    pub fn intersect_with(&mut self, other: &Self) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    pub fn difference_with(&mut self, other: &Self) {
+        for (word, other_word) in self.words.iter_mut().zip(&other.words) {
+            *word &= !other_word;
+        }
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .enumerate()
+            .all(|(i, word)| word & !other.words.get(i).copied().unwrap_or(0) == 0)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(word, other_word)| word & other_word == 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+
+                let bit = word.trailing_zeros() as usize;
+
+                word &= word - 1;
+
+                Some(i * WORD_BITS + bit)
+            })
+        })
+    }
+}
+
+fn split(bit: usize) -> (usize, u64) {
+    (bit / WORD_BITS, 1 << (bit % WORD_BITS))
+}
+
+// Sets with the same bits are equal regardless of capacity
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_subset(other) && other.is_subset(self)
+    }
+}
+
+impl Eq for BitSet {}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(bits: I) -> Self {
+        let mut set = Self::new();
+
+        set.extend(bits);
+
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, bits: I) {
+        for bit in bits {
+            self.insert(bit);
+        }
+    }
+}
+
+macro_rules! impl_set_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $with:ident) => {
+        impl $op_assign<&BitSet> for BitSet {
+            fn $method_assign(&mut self, other: &BitSet) {
+                self.$with(other);
+            }
+        }
+
+        impl $op<&BitSet> for &BitSet {
+            type Output = BitSet;
+
+            fn $method(self, other: &BitSet) -> BitSet {
+                let mut result = self.clone();
+
+                result.$with(other);
+
+                result
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union_with);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersect_with);
+impl_set_op!(Sub, sub, SubAssign, sub_assign, difference_with);
+
+// Coordinates can be any integer type; ones outside the grid are never contained
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    bits: BitSet,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: BitSet::with_capacity(width * height),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn insert<C: TryInto<usize>>(&mut self, pos: (C, C)) -> bool {
+        let index = self.index(pos).expect("position is outside of the grid");
+
+        self.bits.insert(index)
+    }
+
+    pub fn remove<C: TryInto<usize>>(&mut self, pos: (C, C)) -> bool {
+        self.index(pos).is_some_and(|index| self.bits.remove(index))
+    }
+
+    pub fn contains<C: TryInto<usize>>(&self, pos: (C, C)) -> bool {
+        self.index(pos)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn bits(&self) -> &BitSet {
+        &self.bits
+    }
+
+    pub fn bits_mut(&mut self) -> &mut BitSet {
+        &mut self.bits
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.bits
+            .iter()
+            .map(|index| (index % self.width, index / self.width))
+    }
+
+    fn index<C: TryInto<usize>>(&self, (x, y): (C, C)) -> Option<usize> {
+        let x = x.try_into().ok().filter(|&x| x < self.width)?;
+        let y = y.try_into().ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+}
+
+impl fmt::Debug for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let ch = if self.contains((x, y)) { '#' } else { '.' };
+
+                write!(f, "{ch}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+// A grid with `depth` bits per cell, e.g. one per direction a guard can face
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitGrid3 {
+    width: usize,
+    height: usize,
+    depth: usize,
+    bits: BitSet,
+}
+
+impl BitGrid3 {
+    pub fn new(width: usize, height: usize, depth: usize) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+            bits: BitSet::with_capacity(width * height * depth),
+        }
+    }
+
+    pub fn insert<C: TryInto<usize>>(&mut self, pos: (C, C), layer: usize) -> bool {
+        let index = self
+            .index(pos, layer)
+            .expect("position is outside of the grid");
+
+        self.bits.insert(index)
+    }
+
+    pub fn remove<C: TryInto<usize>>(&mut self, pos: (C, C), layer: usize) -> bool {
+        self.index(pos, layer)
+            .is_some_and(|index| self.bits.remove(index))
+    }
+
+    pub fn contains<C: TryInto<usize>>(&self, pos: (C, C), layer: usize) -> bool {
+        self.index(pos, layer)
+            .is_some_and(|index| self.bits.contains(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), usize)> + '_ {
+        self.bits.iter().map(|index| {
+            let (cell, layer) = (index / self.depth, index % self.depth);
+
+            ((cell % self.width, cell / self.width), layer)
+        })
+    }
+
+    // Collapses the layers, keeping every cell that has at least one bit set
+    pub fn flatten(&self) -> BitGrid {
+        let mut grid = BitGrid::new(self.width, self.height);
+
+        for (pos, _) in self.iter() {
+            grid.insert(pos);
+        }
+
+        grid
+    }
+
+    fn index<C: TryInto<usize>>(&self, (x, y): (C, C), layer: usize) -> Option<usize> {
+        let x = x.try_into().ok().filter(|&x| x < self.width)?;
+        let y = y.try_into().ok().filter(|&y| y < self.height)?;
+
+        if layer >= self.depth {
+            return None;
+        }
+
+        Some((y * self.width + x) * self.depth + layer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bits_on_word_boundaries() {
+        let mut set = BitSet::with_capacity(64);
+
+        assert_eq!(set.capacity(), 64);
+
+        for bit in [0, 63, 64, 127, 128] {
+            assert!(set.insert(bit), "{bit}");
+            assert!(!set.insert(bit), "{bit}");
+        }
+
+        assert_eq!(set.capacity(), 192);
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 127, 128]);
+        assert!(set.contains(64) && !set.contains(65) && !set.contains(1000));
+        assert!(set.remove(63));
+        assert!(!set.remove(63));
+        assert!(!set.remove(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 64, 127, 128]);
+
+        set.clear();
+
+        assert!(set.is_empty());
+        assert_eq!(set, BitSet::new());
+    }
+
+    #[test]
+    fn set_operations_across_capacities() {
+        let small = BitSet::from_iter([1, 2, 63]);
+        let large = BitSet::from_iter([2, 63, 64, 200]);
+
+        assert_eq!(&small | &large, BitSet::from_iter([1, 2, 63, 64, 200]));
+        assert_eq!(&large | &small, BitSet::from_iter([1, 2, 63, 64, 200]));
+        assert_eq!(&small & &large, BitSet::from_iter([2, 63]));
+        assert_eq!(&large & &small, BitSet::from_iter([2, 63]));
+        assert_eq!(&small - &large, BitSet::from_iter([1]));
+        assert_eq!(&large - &small, BitSet::from_iter([64, 200]));
+        assert!(BitSet::from_iter([2, 63]).is_subset(&large));
+        assert!(!large.is_subset(&small));
+        assert!(!small.is_disjoint(&large));
+        assert!(BitSet::from_iter([1, 300]).is_disjoint(&large));
+
+        // Equality ignores capacity
+        let mut grown = BitSet::from_iter([1, 2, 63, 500]);
+
+        grown.remove(500);
+
+        assert_eq!(grown, small);
+    }
+
+    #[test]
+    fn grid_bounds() {
+        let mut grid = BitGrid::new(10, 7);
+
+        assert!(grid.insert((0, 0)));
+        assert!(grid.insert((9i32, 6i32)));
+        assert!(!grid.insert((9u8, 6u8)));
+        assert!(grid.contains((9, 6)));
+
+        for outside in [(-1, 0), (0, -1), (10, 0), (0, 7), (-1, -1)] {
+            assert!(!grid.contains(outside), "{outside:?}");
+            assert!(!grid.remove(outside), "{outside:?}");
+        }
+
+        // `(3, 6)` and `(4, 6)` are the last bit of one word and the first of the next
+        grid.insert((3, 6));
+        grid.insert((4, 6));
+
+        assert_eq!(grid.len(), 4);
+        assert_eq!(
+            grid.iter().collect::<Vec<_>>(),
+            [(0, 0), (3, 6), (4, 6), (9, 6)]
+        );
+
+        let mut small = BitGrid::new(3, 2);
+
+        small.insert((1, 0));
+        small.insert((2, 1));
+
+        assert_eq!(format!("{small:?}"), ".#.\n..#\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "position is outside of the grid")]
+    fn grid_insert_outside_panics() {
+        BitGrid::new(10, 7).insert((10, 0));
+    }
+
+    #[test]
+    fn grid3_layers() {
+        let mut states = BitGrid3::new(10, 7, 4);
+
+        assert!(states.insert((3, 6), 3));
+        assert!(states.insert((4, 6), 0));
+        assert!(states.insert((4, 6), 2));
+        assert!(!states.insert((4, 6), 2));
+        assert!(states.contains((4, 6), 2));
+        assert!(!states.contains((4, 6), 1));
+        assert!(!states.contains((4, 6), 4));
+        assert!(!states.contains((-1, 6), 0));
+        assert!(!states.remove((4, 7), 0));
+        assert_eq!(states.len(), 3);
+        assert_eq!(
+            states.iter().collect::<Vec<_>>(),
+            [((3, 6), 3), ((4, 6), 0), ((4, 6), 2)]
+        );
+        assert_eq!(
+            states.flatten().iter().collect::<Vec<_>>(),
+            [(3, 6), (4, 6)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "position is outside of the grid")]
+    fn grid3_insert_beyond_the_last_layer_panics() {
+        BitGrid3::new(10, 7, 4).insert((0, 0), 4);
+    }
+}
@@ -0,0 +1,218 @@
+use fnv::FnvHashMap;
+use std::hash::Hash;
+
+// A sequence that repeats with period `len` after `start` initial states (μ and λ)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    // Maps step `n` to the earliest step with the same state
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            return n;
+        }
+
+        self.start + (n - self.start) % self.len
+    }
+}
+
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut len = 1;
+    hare = step(&tortoise);
+
+    while tortoise != hare {
+        hare = step(&hare);
+        len += 1;
+    }
+
+    Cycle { start, len }
+}
+
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+
+        hare = step(&hare);
+        len += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+
+    for _ in 0..len {
+        hare = step(&hare);
+    }
+
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, len }
+}
+
+// Remembers every state, so unlike `floyd` and `brent` this also works
+// for sequences that end without repeating, in which case it returns `None`.
+pub fn find_cycle<S, I>(states: I) -> Option<Cycle>
+where
+    S: Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = FnvHashMap::default();
+
+    for (i, state) in states.into_iter().enumerate() {
+        if let Some(start) = seen.insert(state, i) {
+            return Some(Cycle {
+                start,
+                len: i - start,
+            });
+        }
+    }
+
+    None
+}
+
+pub fn state_at<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = FnvHashMap::default();
+    let mut states = Vec::new();
+    let mut state = initial;
+
+    for i in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                len: i - start,
+            };
+
+            return states.swap_remove(cycle.reduce(n));
+        }
+
+        seen.insert(state.clone(), i);
+        states.push(state.clone());
+        state = step(&state);
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 → 1 → 2 → 3 → 4 → 5 → 3 → …
+    const RHO: [usize; 6] = [1, 2, 3, 4, 5, 3];
+
+    fn rho(state: &usize) -> usize {
+        RHO[*state]
+    }
+
+    fn sequence<S: Clone>(initial: S, mut step: impl FnMut(&S) -> S) -> impl Iterator<Item = S> {
+        std::iter::successors(Some(initial), move |state| Some(step(state)))
+    }
+
+    #[test]
+    fn finds_start_and_len() {
+        let expected = Cycle { start: 3, len: 3 };
+
+        assert_eq!(floyd(0, rho), expected);
+        assert_eq!(brent(0, rho), expected);
+        assert_eq!(find_cycle(sequence(0, rho)), Some(expected));
+
+        // Starting inside the cycle, or at a fixed point
+        assert_eq!(floyd(4, rho), Cycle { start: 0, len: 3 });
+        assert_eq!(brent(4, rho), Cycle { start: 0, len: 3 });
+        assert_eq!(floyd(7, |&n| n), Cycle { start: 0, len: 1 });
+        assert_eq!(brent(7, |&n| n), Cycle { start: 0, len: 1 });
+    }
+
+    #[test]
+    fn algorithms_agree() {
+        for modulus in [7, 10, 97, 1000, 4096] {
+            for offset in 1..5 {
+                let step = |n: &u64| (n * n + offset) % modulus;
+                let expected = find_cycle(sequence(2, step));
+
+                assert_eq!(
+                    Some(floyd(2, step)),
+                    expected,
+                    "x² + {offset} mod {modulus}"
+                );
+                assert_eq!(
+                    Some(brent(2, step)),
+                    expected,
+                    "x² + {offset} mod {modulus}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sequences_that_end_have_no_cycle() {
+        assert_eq!(find_cycle(0..100), None);
+        assert_eq!(find_cycle("abcb".chars()), Some(Cycle { start: 1, len: 2 }));
+    }
+
+    #[test]
+    fn reduces_steps_past_the_start() {
+        let cycle = Cycle { start: 3, len: 3 };
+        let reduced = (0..10).map(|n| cycle.reduce(n)).collect::<Vec<_>>();
+
+        assert_eq!(reduced, [0, 1, 2, 3, 4, 5, 3, 4, 5, 3]);
+    }
+
+    #[test]
+    fn states_past_the_start() {
+        let expected = sequence(0, rho).take(20).collect::<Vec<_>>();
+
+        for (n, &state) in expected.iter().enumerate() {
+            assert_eq!(state_at(0, rho, n), state, "state {n}");
+        }
+
+        assert_eq!(state_at(0, rho, 1_000_000_000_000), 4);
+        assert_eq!(state_at(String::from("a"), |s| s.clone(), 5), "a");
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+// An error in an input, displayed with the offending line and the error underlined:
+//
+// ```text
+// error: unexpected 'x', expected one of "#.O@"
+//  --> line 3, column 4
+//   |
+// 3 | #..x.#
+//   |    ^
+// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+    excerpt: String,
+    width: usize,
+}
+
+impl Diagnostic {
+    // `len` bytes from `offset` on are underlined, but at least one char and never past the line
+    pub fn new(input: &str, offset: usize, len: usize, message: String) -> Self {
+        let offset = offset.min(input.len());
+        let start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+        // Lines ending in `\r\n` are excerpted and underlined without the `\r`
+        let end = match input[..end].strip_suffix('\r') {
+            Some(line) if line.len() >= offset => line.len(),
+            _ => end,
+        };
+        let underlined = &input[offset..(offset + len).min(end)];
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[start..offset].chars().count() + 1,
+            message,
+            excerpt: input[start..end].to_string(),
+            width: underlined.chars().count().max(1),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(f, "{gutter}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.excerpt)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_line_and_column() {
+        let input = "#....\n#..x.\n#....\n";
+        let diagnostic = Diagnostic::new(input, 9, 1, "unexpected 'x'".into());
+
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 4));
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: unexpected 'x'\n --> line 2, column 4\n  |\n2 | #..x.\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn underlines_at_least_one_char_and_never_past_the_line() {
+        let input = "abc\ndef\n";
+
+        assert!(Diagnostic::new(input, 5, 0, String::new())
+            .to_string()
+            .ends_with("\n  |  ^"));
+        assert!(Diagnostic::new(input, 5, 100, String::new())
+            .to_string()
+            .ends_with("\n2 | def\n  |  ^^"));
+
+        // Past the end of a line, or of the whole input
+        let end_of_line = Diagnostic::new(input, 3, 1, String::new());
+        let end_of_input = Diagnostic::new(input, 100, 1, String::new());
+
+        assert_eq!((end_of_line.line, end_of_line.column), (1, 4));
+        assert_eq!((end_of_input.line, end_of_input.column), (3, 1));
+    }
+
+    #[test]
+    fn counts_columns_in_chars() {
+        let input = "päivää x";
+        let diagnostic = Diagnostic::new(input, input.find('x').unwrap(), 1, String::new());
+
+        assert_eq!(diagnostic.column, 8);
+        assert!(diagnostic.to_string().ends_with("\n  |        ^"));
+        assert!(Diagnostic::new(input, 1, 2, String::new())
+            .to_string()
+            .ends_with("\n  |  ^"));
+    }
+
+    #[test]
+    fn leaves_out_carriage_returns() {
+        let input = "ok\r\nbad\r\nok\r\n";
+        let diagnostic = Diagnostic::new(input, 4, 100, "line does not match".into());
+
+        assert_eq!((diagnostic.line, diagnostic.column), (2, 1));
+        assert!(diagnostic.to_string().ends_with("\n2 | bad\n  | ^^^"));
+    }
+
+    #[test]
+    fn widens_the_gutter_for_long_line_numbers() {
+        let input = "x\n".repeat(12);
+        let diagnostic = Diagnostic::new(&input, 22, 1, "twelfth".into());
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: twelfth\n  --> line 12, column 1\n   |\n12 | x\n   | ^"
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Default)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    num_components: usize,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            num_components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn add(&mut self) -> usize {
+        let index = self.parents.len();
+
+        self.parents.push(index);
+        self.sizes.push(1);
+        self.num_components += 1;
+
+        index
+    }
+
+    pub fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut index = index;
+
+        while self.parents[index] != root {
+            let parent = self.parents[index];
+
+            self.parents[index] = root;
+            index = parent;
+        }
+
+        root
+    }
+
+    // Returns `false` if both were already in the same component
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.num_components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn size_of(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+
+        self.sizes[root]
+    }
+
+    pub fn num_components(&self) -> usize {
+        self.num_components
+    }
+
+    pub fn members(&mut self, index: usize) -> impl Iterator<Item = usize> + use<'_> {
+        let root = self.find(index);
+
+        (0..self.len()).filter(move |&other| self.find(other) == root)
+    }
+
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut components = HashMap::<usize, Vec<usize>>::new();
+
+        for index in 0..self.len() {
+            let root = self.find(index);
+
+            components.entry(root).or_default().push(index);
+        }
+
+        let mut components = components.into_values().collect::<Vec<_>>();
+
+        components.sort_unstable_by_key(|component| component[0]);
+
+        components
+    }
+}
+
+// Keys are added by `insert` and `union`. Keys that never were are absent: they aren't
+// connected to anything, not even to themselves, and have no members.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    union_find: UnionFind,
+}
+
+impl<K> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            union_find: UnionFind::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&index) = self.indices.get(&key) {
+            return index;
+        }
+
+        let index = self.union_find.add();
+
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key);
+
+        index
+    }
+
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+
+    pub fn key(&self, index: usize) -> &K {
+        &self.keys[index]
+    }
+
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = self.index_of(key)?;
+        let root = self.union_find.find(index);
+
+        Some(&self.keys[root])
+    }
+
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let a = self.insert(a);
+        let b = self.insert(b);
+
+        self.union_find.union(a, b)
+    }
+
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(a), Some(b)) => self.union_find.connected(a, b),
+            _ => false,
+        }
+    }
+
+    pub fn size_of(&mut self, key: &K) -> usize {
+        match self.index_of(key) {
+            Some(index) => self.union_find.size_of(index),
+            None => 0,
+        }
+    }
+
+    pub fn num_components(&self) -> usize {
+        self.union_find.num_components()
+    }
+
+    pub fn members(&mut self, key: &K) -> Vec<&K> {
+        let Some(index) = self.index_of(key) else {
+            return Vec::new();
+        };
+
+        let members = self.union_find.members(index).collect::<Vec<_>>();
+
+        members.into_iter().map(|index| &self.keys[index]).collect()
+    }
+
+    pub fn components(&mut self) -> Vec<Vec<&K>> {
+        self.union_find
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| &self.keys[index])
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_and_find() {
+        let mut sets = UnionFind::new(6);
+
+        assert_eq!(sets.num_components(), 6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(sets.find(2), sets.find(1));
+        assert_eq!(sets.size_of(3), 4);
+        assert_eq!(sets.size_of(5), 1);
+        assert_eq!(sets.num_components(), 3);
+        assert_eq!(sets.members(2).collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+        let added = sets.add();
+
+        assert_eq!(added, 6);
+        assert_eq!(sets.len(), 7);
+        assert_eq!(sets.num_components(), 4);
+    }
+
+    #[test]
+    fn components_are_ordered_by_their_first_member() {
+        let mut sets = UnionFind::new(7);
+
+        sets.union(5, 2);
+        sets.union(6, 0);
+        sets.union(4, 2);
+
+        assert_eq!(
+            sets.components(),
+            [vec![0, 6], vec![1], vec![2, 4, 5], vec![3]]
+        );
+        assert!(UnionFind::new(0).components().is_empty());
+    }
+
+    #[test]
+    fn keyed_union_find() {
+        let mut plots = KeyedUnionFind::new();
+
+        plots.insert((0, 0));
+        plots.union((0, 0), (1, 0));
+        plots.union((5, 5), (5, 6));
+        plots.union((1, 0), (1, 1));
+
+        assert_eq!(plots.len(), 5);
+        assert!(plots.contains(&(1, 1)));
+        assert_eq!(plots.index_of(&(5, 5)), Some(2));
+        assert_eq!(plots.key(2), &(5, 5));
+        assert!(plots.connected(&(0, 0), &(1, 1)));
+        assert!(!plots.connected(&(0, 0), &(5, 6)));
+        assert_eq!(plots.find(&(1, 1)).copied(), plots.find(&(0, 0)).copied());
+        assert_eq!(plots.size_of(&(1, 0)), 3);
+        assert_eq!(plots.num_components(), 2);
+        assert_eq!(plots.members(&(5, 6)), [&(5, 5), &(5, 6)]);
+        assert_eq!(
+            plots.components(),
+            [vec![&(0, 0), &(1, 0), &(1, 1)], vec![&(5, 5), &(5, 6)]]
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_absent() {
+        let mut plots = KeyedUnionFind::new();
+
+        plots.insert('a');
+
+        assert!(!plots.contains(&'z'));
+        assert_eq!(plots.index_of(&'z'), None);
+        assert_eq!(plots.find(&'z'), None);
+        assert!(!plots.connected(&'z', &'z'));
+        assert!(!plots.connected(&'a', &'z'));
+        assert_eq!(plots.size_of(&'z'), 0);
+        assert!(plots.members(&'z').is_empty());
+
+        // Asking about them doesn't add them
+        assert_eq!(plots.len(), 1);
+        assert_eq!(plots.num_components(), 1);
+    }
+}
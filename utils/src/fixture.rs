@@ -0,0 +1,104 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+// An example input with the answers the puzzle description gives for it.
+// Stored in a day crate's `fixtures/` directory as a header followed by the input:
+//
+// ```text
+// part1: 11
+// part2: 31
+// ---
+// 3   4
+// 4   3
+// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+const SEPARATOR: &str = "---\n";
+
+impl Fixture {
+    pub fn new(name: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            input: input.into(),
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn parse(name: impl Into<String>, contents: &str) -> Self {
+        let name = name.into();
+        let contents = contents.replace("\r\n", "\n");
+        let (header, input) = contents
+            .split_once(SEPARATOR)
+            .unwrap_or_else(|| panic!("fixture {name} has no `---` line"));
+        let mut fixture = Self::new(name, input);
+
+        for line in header.lines().filter(|line| !line.trim().is_empty()) {
+            let (key, value) = line
+                .split_once(':')
+                .unwrap_or_else(|| panic!("fixture {}: invalid line {line:?}", fixture.name));
+            let value = Some(value.trim().to_string());
+
+            match key.trim() {
+                "part1" => fixture.part1 = value,
+                "part2" => fixture.part2 = value,
+                key => panic!("fixture {}: unknown key {key:?}", fixture.name),
+            }
+        }
+
+        fixture
+    }
+
+    pub fn answer(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    // All `*.txt` fixtures of a crate, sorted by name; `crate_dir` is usually
+    // `env!("CARGO_MANIFEST_DIR")`.
+    pub fn load_all(crate_dir: impl AsRef<Path>) -> Vec<Self> {
+        let dir = crate_dir.as_ref().join("fixtures");
+        let Ok(entries) = fs::read_dir(&dir) else {
+            return Vec::new();
+        };
+
+        let mut fixtures = entries
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .map(|path| {
+                let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+                let contents = fs::read_to_string(&path).unwrap();
+
+                Self::parse(name, &contents)
+            })
+            .collect::<Vec<_>>();
+
+        fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+
+        fixtures
+    }
+}
+
+impl fmt::Display for Fixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(answer) = &self.part1 {
+            writeln!(f, "part1: {answer}")?;
+        }
+
+        if let Some(answer) = &self.part2 {
+            writeln!(f, "part2: {answer}")?;
+        }
+
+        write!(f, "{SEPARATOR}{}", self.input)
+    }
+}
@@ -0,0 +1,475 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+#[cfg(feature = "image")]
+use std::fs;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::viz::{Color, Frame, Visualizer};
+
+// Colours for arbitrary cell labels, e.g. the chars of a grid or region ids.
+// Labels without a colour get the fallback, or one derived from their hash.
+#[derive(Debug, Clone)]
+pub struct LabelColors<L> {
+    colors: HashMap<L, Color>,
+    fallback: Option<Color>,
+}
+
+impl<L: Hash + Eq> LabelColors<L> {
+    pub fn new() -> Self {
+        Self {
+            colors: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    pub fn color(mut self, label: L, color: Color) -> Self {
+        self.colors.insert(label, color);
+        self
+    }
+
+    pub fn fallback(mut self, color: Color) -> Self {
+        self.fallback = Some(color);
+        self
+    }
+
+    pub fn get(&self, label: &L) -> Color {
+        if let Some(&color) = self.colors.get(label) {
+            return color;
+        }
+
+        self.fallback.unwrap_or_else(|| {
+            let mut hasher = DefaultHasher::new();
+
+            label.hash(&mut hasher);
+            hue_color(hasher.finish())
+        })
+    }
+}
+
+impl<L: Hash + Eq> Default for LabelColors<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Spreads hashes over bright hues, so that neighbouring labels are usually told apart
+fn hue_color(hash: u64) -> Color {
+    let hue = (hash % 360) as f64 / 60.0;
+    let rise = (hue.fract() * 200.0) as u8;
+    let fall = 200 - rise;
+    let (r, g, b) = match hue as u32 {
+        0 => (200, rise, 0),
+        1 => (fall, 200, 0),
+        2 => (0, 200, rise),
+        3 => (0, fall, 200),
+        4 => (rise, 0, 200),
+        _ => (200, 0, fall),
+    };
+
+    Color::Rgb(r + 40, g + 40, b + 40)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Overlay {
+    points: Vec<(usize, usize)>,
+    color: Color,
+}
+
+// A grid of coloured cells with paths drawn through cell centres on top.
+// Rendered with `scale` pixels per cell; SVGs stay vector graphics of that size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    scale: usize,
+    cells: Vec<Color>,
+    overlays: Vec<Overlay>,
+}
+
+impl Picture {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Self::from_fn(width, height, |_, _| background)
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> Color) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self {
+            width,
+            height,
+            scale: 1,
+            cells,
+            overlays: Vec::new(),
+        }
+    }
+
+    pub fn from_labels<L: Hash + Eq>(
+        width: usize,
+        height: usize,
+        mut label: impl FnMut(usize, usize) -> L,
+        colors: &LabelColors<L>,
+    ) -> Self {
+        Self::from_fn(width, height, |x, y| colors.get(&label(x, y)))
+    }
+
+    // Cells use their background colour, then their foreground colour and are black otherwise
+    pub fn from_frame(frame: &Frame) -> Self {
+        Self::from_fn(frame.width(), frame.height(), |x, y| {
+            let cell = frame.get((x, y)).unwrap();
+
+            cell.bg.or(cell.fg).unwrap_or(Color::Black)
+        })
+    }
+
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get<C: TryInto<usize>>(&self, pos: (C, C)) -> Option<Color> {
+        self.index(pos).map(|index| self.cells[index])
+    }
+
+    // Cells outside the picture are ignored
+    pub fn set<C: TryInto<usize>>(&mut self, pos: (C, C), color: Color) {
+        if let Some(index) = self.index(pos) {
+            self.cells[index] = color;
+        }
+    }
+
+    // Points outside the picture end the path, as there is nothing to draw them on
+    pub fn path<C: TryInto<usize>>(
+        &mut self,
+        points: impl IntoIterator<Item = (C, C)>,
+        color: Color,
+    ) {
+        let points = points
+            .into_iter()
+            .map_while(|(x, y)| {
+                let x = x.try_into().ok().filter(|&x| x < self.width)?;
+                let y = y.try_into().ok().filter(|&y| y < self.height)?;
+
+                Some((x, y))
+            })
+            .collect();
+
+        self.overlays.push(Overlay { points, color });
+    }
+
+    pub fn pixel_size(&self) -> (usize, usize) {
+        (self.width * self.scale, self.height * self.scale)
+    }
+
+    // Row-major RGB triples, three bytes per pixel
+    pub fn pixels(&self) -> Vec<u8> {
+        let (width, height) = self.pixel_size();
+        let mut pixels = vec![0; width * height * 3];
+        let mut fill = |(px, py): (usize, usize), size: usize, color: Color| {
+            let (r, g, b) = color.rgb();
+
+            for y in py..(py + size).min(height) {
+                for x in px..(px + size).min(width) {
+                    let index = (y * width + x) * 3;
+
+                    pixels[index..index + 3].copy_from_slice(&[r, g, b]);
+                }
+            }
+        };
+
+        for (index, &color) in self.cells.iter().enumerate() {
+            let (x, y) = (index % self.width, index / self.width);
+
+            fill((x * self.scale, y * self.scale), self.scale, color);
+        }
+
+        let thickness = (self.scale / 3).max(1);
+        let centre = |(x, y): (usize, usize)| {
+            let offset = (self.scale - thickness) as f64 / 2.0;
+
+            (
+                (x * self.scale) as f64 + offset,
+                (y * self.scale) as f64 + offset,
+            )
+        };
+
+        for overlay in &self.overlays {
+            for (index, &point) in overlay.points.iter().enumerate() {
+                let (x0, y0) = centre(point);
+                let (x1, y1) = centre(overlay.points.get(index + 1).copied().unwrap_or(point));
+                let steps = (x1 - x0).abs().max((y1 - y0).abs()).ceil() as usize;
+
+                for step in 0..=steps {
+                    let t = step as f64 / steps.max(1) as f64;
+                    let x = x0 + (x1 - x0) * t;
+                    let y = y0 + (y1 - y0) * t;
+
+                    fill(
+                        (x.round() as usize, y.round() as usize),
+                        thickness,
+                        overlay.color,
+                    );
+                }
+            }
+        }
+
+        pixels
+    }
+
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        let (width, height) = self.pixel_size();
+
+        write!(out, "P6\n{width} {height}\n255\n")?;
+        out.write_all(&self.pixels())
+    }
+
+    #[cfg(feature = "image")]
+    pub fn write_png(&self, out: impl Write) -> io::Result<()> {
+        let (width, height) = self.pixel_size();
+        let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&self.pixels())?;
+
+        Ok(())
+    }
+
+    // Runs of equal cells within a row become one rectangle, which keeps large grids small
+    pub fn write_svg(&self, mut out: impl Write) -> io::Result<()> {
+        let (width, height) = self.pixel_size();
+        let mut svg = String::new();
+
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {} {}" shape-rendering="crispEdges">"#,
+            self.width, self.height
+        )
+        .unwrap();
+
+        for (y, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            let mut x = 0;
+
+            for run in row.chunk_by(|a, b| a == b) {
+                writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{y}" width="{}" height="1" fill="{}"/>"#,
+                    run.len(),
+                    hex(run[0])
+                )
+                .unwrap();
+
+                x += run.len();
+            }
+        }
+
+        for overlay in &self.overlays {
+            let points = overlay
+                .points
+                .iter()
+                .map(|(x, y)| format!("{}.5,{}.5", x, y))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            writeln!(
+                svg,
+                r#"<polyline points="{points}" fill="none" stroke="{}" stroke-width="0.3" stroke-linecap="square" stroke-linejoin="round" shape-rendering="auto"/>"#,
+                hex(overlay.color)
+            )
+            .unwrap();
+        }
+
+        svg.push_str("</svg>\n");
+
+        out.write_all(svg.as_bytes())
+    }
+
+    // The format is picked by extension: `ppm`, `png` (with the `image` feature) or `svg`
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        let write: fn(&Self, &mut BufWriter<File>) -> io::Result<()> = match extension {
+            Some("ppm") => |picture, out| picture.write_ppm(out),
+            #[cfg(feature = "image")]
+            Some("png") => |picture, out| picture.write_png(out),
+            #[cfg(not(feature = "image"))]
+            Some("png") => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    format!("{}: png needs the `image` feature", path.display()),
+                ))
+            }
+            Some("svg") => |picture, out| picture.write_svg(out),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: unknown image format", path.display()),
+                ))
+            }
+        };
+        let mut out = BufWriter::new(File::create(path)?);
+
+        write(self, &mut out)?;
+        out.flush()
+    }
+
+    fn index<C: TryInto<usize>>(&self, (x, y): (C, C)) -> Option<usize> {
+        let x = x.try_into().ok().filter(|&x| x < self.width)?;
+        let y = y.try_into().ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+}
+
+fn hex(color: Color) -> String {
+    let (r, g, b) = color.rgb();
+
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+// Collects pictures, e.g. simulation frames sent to it as a `Visualizer`
+#[derive(Debug, Clone)]
+pub struct Animation {
+    pictures: Vec<Picture>,
+    delay: Duration,
+    scale: usize,
+}
+
+impl Animation {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            pictures: Vec::new(),
+            delay,
+            scale: 1,
+        }
+    }
+
+    // Applied to frames shown through `Visualizer`; pushed pictures keep their own scale
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn push(&mut self, picture: Picture) {
+        self.pictures.push(picture);
+    }
+
+    pub fn pictures(&self) -> &[Picture] {
+        &self.pictures
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    pub fn len(&self) -> usize {
+        self.pictures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pictures.is_empty()
+    }
+
+    #[cfg(feature = "image")]
+    // Loops forever. Pictures with at most 256 colours are stored losslessly,
+    // others are quantized.
+    pub fn write_gif(&self, out: impl Write) -> io::Result<()> {
+        let sizes = self.pictures.iter().map(Picture::pixel_size);
+        let width = sizes.clone().map(|(width, _)| width).max().unwrap_or(1);
+        let height = sizes.map(|(_, height)| height).max().unwrap_or(1);
+        let size = |n: usize| {
+            u16::try_from(n).map_err(|_| io::Error::other("picture is too large for a gif"))
+        };
+        let mut encoder =
+            gif::Encoder::new(out, size(width)?, size(height)?, &[]).map_err(io::Error::other)?;
+        let delay = (self.delay.as_millis() / 10).min(u16::MAX.into()) as u16;
+
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+
+        for picture in &self.pictures {
+            let (width, height) = picture.pixel_size();
+            let (width, height) = (size(width)?, size(height)?);
+            let pixels = picture.pixels();
+            let mut frame = match indexed(&pixels) {
+                Some((indices, palette)) => {
+                    gif::Frame::from_palette_pixels(width, height, indices, palette, None)
+                }
+                None => gif::Frame::from_rgb_speed(width, height, &pixels, 10),
+            };
+
+            frame.delay = delay;
+            frame.dispose = gif::DisposalMethod::Background;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "image")]
+    pub fn save_gif(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+
+        self.write_gif(&mut out)?;
+        out.flush()
+    }
+
+    #[cfg(feature = "image")]
+    // Writes `0000.png`, `0001.png`, … into `dir`, creating it if needed
+    pub fn save_png_series(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+
+        fs::create_dir_all(dir)?;
+
+        for (index, picture) in self.pictures.iter().enumerate() {
+            picture.save(dir.join(format!("{index:04}.png")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Visualizer for Animation {
+    fn show(&mut self, frame: Frame) {
+        self.push(Picture::from_frame(&frame).scale(self.scale));
+    }
+}
+
+#[cfg(feature = "image")]
+fn indexed(pixels: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut palette = HashMap::<&[u8], u8>::new();
+    let mut colors = Vec::new();
+    let mut indices = Vec::with_capacity(pixels.len() / 3);
+
+    for pixel in pixels.chunks(3) {
+        let next = palette.len();
+        let index = *palette.entry(pixel).or_insert_with(|| {
+            colors.extend_from_slice(pixel);
+            next as u8
+        });
+
+        if palette.len() > 256 {
+            return None;
+        }
+
+        indices.push(index);
+    }
+
+    Some((indices, colors))
+}
@@ -0,0 +1,482 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter::Peekable;
+use std::ops::{Range, RangeInclusive};
+
+use crate::num::Integer;
+
+// Half-open `start..end`; use `closed` and `last` for the inclusive view
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn closed(first: T, last: T) -> Self {
+        Self::new(first, last + T::ONE)
+    }
+
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn last(&self) -> T {
+        self.end - T::ONE
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            return T::ZERO;
+        }
+
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    // True if one interval ends exactly where the other starts
+    pub fn contiguous_with(&self, other: &Self) -> bool {
+        self.end == other.start || other.end == self.start
+    }
+
+    // Smallest interval covering both, including anything in between
+    pub fn joined_with(&self, other: &Self) -> Self {
+        Self::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+impl<T: Integer> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (first, last) = range.into_inner();
+
+        Self::closed(first, last)
+    }
+}
+
+impl<T> From<Interval<T>> for Range<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..interval.end
+    }
+}
+
+// Disjoint intervals keyed by start. Touching intervals are merged on insert,
+// so every set of values has exactly one representation.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn num_intervals(&self) -> usize {
+        self.intervals.len()
+    }
+
+    // Number of values covered by all intervals
+    pub fn len(&self) -> T {
+        self.iter()
+            .fold(T::ZERO, |len, interval| len + interval.len())
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .iter()
+            .map(|(&start, &end)| Interval::new(start, end))
+    }
+
+    pub fn insert(&mut self, interval: impl Into<Interval<T>>) {
+        let Interval { mut start, mut end } = interval.into();
+
+        if start >= end {
+            return;
+        }
+
+        if let Some((&previous_start, &previous_end)) = self.intervals.range(..=start).next_back() {
+            if previous_end >= start {
+                start = previous_start;
+            }
+        }
+
+        let absorbed = self
+            .intervals
+            .range(start..=end)
+            .map(|(&start, _)| start)
+            .collect::<Vec<_>>();
+
+        for absorbed_start in absorbed {
+            let absorbed_end = self.intervals.remove(&absorbed_start).unwrap();
+
+            end = end.max(absorbed_end);
+        }
+
+        self.intervals.insert(start, end);
+    }
+
+    pub fn remove(&mut self, interval: impl Into<Interval<T>>) {
+        let removed = interval.into();
+
+        if removed.is_empty() {
+            return;
+        }
+
+        let overlapping = self.overlapping(removed).collect::<Vec<_>>();
+
+        for interval in overlapping {
+            self.intervals.remove(&interval.start);
+
+            if interval.start < removed.start {
+                self.intervals.insert(interval.start, removed.start);
+            }
+
+            if interval.end > removed.end {
+                self.intervals.insert(removed.end, interval.end);
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for interval in other.iter() {
+            self.insert(interval);
+        }
+    }
+
+    // Moves all values `>= at` into the returned set
+    pub fn split_off(&mut self, at: T) -> Self {
+        let mut upper = Self {
+            intervals: self.intervals.split_off(&at),
+        };
+
+        if let Some(last) = self.intervals.values_mut().next_back() {
+            if *last > at {
+                upper.intervals.insert(at, *last);
+                *last = at;
+            }
+        }
+
+        upper
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.interval_containing(value).is_some()
+    }
+
+    pub fn interval_containing(&self, value: T) -> Option<Interval<T>> {
+        let (&start, &end) = self.intervals.range(..=value).next_back()?;
+
+        (value < end).then_some(Interval::new(start, end))
+    }
+
+    pub fn overlaps(&self, interval: impl Into<Interval<T>>) -> bool {
+        self.overlapping(interval).next().is_some()
+    }
+
+    // Stored intervals that share at least one value with `interval`, unclipped
+    pub fn overlapping(
+        &self,
+        interval: impl Into<Interval<T>>,
+    ) -> impl Iterator<Item = Interval<T>> + '_ {
+        let interval = interval.into();
+        let previous = self
+            .intervals
+            .range(..interval.start)
+            .next_back()
+            .map(|(&start, &end)| Interval::new(start, end))
+            .filter(|previous| previous.overlaps(&interval));
+        let inner = self
+            .intervals
+            .range(interval.start..interval.end.max(interval.start))
+            .map(|(&start, &end)| Interval::new(start, end));
+
+        previous.into_iter().chain(inner)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+
+        for interval in self.iter() {
+            for overlap in other.overlapping(interval) {
+                if let Some(overlap) = overlap.intersection(&interval) {
+                    intersection.insert(overlap);
+                }
+            }
+        }
+
+        intersection
+    }
+
+    // Parts of `bounds` that are not covered by this set
+    pub fn gaps(&self, bounds: impl Into<Interval<T>>) -> Self {
+        let mut gaps = Self::new();
+        let bounds = bounds.into();
+
+        gaps.insert(bounds);
+
+        for interval in self.overlapping(bounds) {
+            gaps.remove(interval);
+        }
+
+        gaps
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.intervals.iter().map(|(start, end)| start..end))
+            .finish()
+    }
+}
+
+impl<T: Integer, I: Into<Interval<T>>> Extend<I> for RangeSet<T> {
+    fn extend<It: IntoIterator<Item = I>>(&mut self, intervals: It) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Integer, I: Into<Interval<T>>> FromIterator<I> for RangeSet<T> {
+    fn from_iter<It: IntoIterator<Item = I>>(intervals: It) -> Self {
+        let mut set = Self::new();
+
+        set.extend(intervals);
+
+        set
+    }
+}
+
+pub trait RunsExt: Iterator + Sized {
+    // Coalesces a sorted iterator of integers into runs of consecutive values,
+    // e.g. `[1, 2, 3, 5, 5, 6]` becomes `[1..4, 5..7]`
+    fn runs(self) -> Runs<Self> {
+        Runs {
+            values: self.peekable(),
+        }
+    }
+}
+
+impl<T: Integer, I: Iterator<Item = T>> RunsExt for I {}
+
+pub struct Runs<I: Iterator> {
+    values: Peekable<I>,
+}
+
+impl<T: Integer, I: Iterator<Item = T>> Iterator for Runs<I> {
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.values.next()?;
+        let mut end = start + T::ONE;
+
+        while let Some(&value) = self.values.peek() {
+            if value > end {
+                break;
+            }
+
+            end = end.max(value + T::ONE);
+            self.values.next();
+        }
+
+        Some(Interval::new(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &RangeSet<i32>) -> String {
+        format!("{set:?}")
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::from(2..=4);
+
+        assert_eq!(interval, Interval::new(2, 5));
+        assert_eq!(interval, Interval::with_len(2, 3));
+        assert_eq!((interval.len(), interval.last()), (3, 4));
+        assert!(interval.contains(4) && !interval.contains(5));
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert!(interval.contiguous_with(&Interval::new(5, 7)));
+        assert!(!interval.overlaps(&Interval::new(5, 7)));
+        assert_eq!(
+            interval.intersection(&Interval::new(3, 9)),
+            Some(Interval::new(3, 5))
+        );
+        assert_eq!(interval.intersection(&Interval::new(5, 9)), None);
+        assert_eq!(
+            interval.joined_with(&Interval::new(8, 9)),
+            Interval::new(2, 9)
+        );
+        assert_eq!(
+            interval.split_at(3),
+            (Interval::new(2, 3), Interval::new(3, 5))
+        );
+        assert_eq!(
+            interval.split_at(9),
+            (Interval::new(2, 5), Interval::new(5, 5))
+        );
+    }
+
+    #[test]
+    fn insert_merges_touching_and_overlapping_intervals() {
+        let mut set = RangeSet::new();
+
+        set.insert(0..3);
+        set.insert(3..5);
+        assert_eq!(ranges(&set), "{0..5}");
+
+        set.insert(7..9);
+        set.insert(12..14);
+        set.insert(6..6);
+        assert_eq!(ranges(&set), "{0..5, 7..9, 12..14}");
+
+        set.insert(8..12);
+        assert_eq!(ranges(&set), "{0..5, 7..14}");
+
+        set.insert(1..2);
+        set.insert(-2..=-1);
+        assert_eq!(ranges(&set), "{-2..5, 7..14}");
+        assert_eq!((set.len(), set.num_intervals()), (14, 2));
+
+        set.insert(-5..20);
+        assert_eq!(ranges(&set), "{-5..20}");
+    }
+
+    #[test]
+    fn remove_splits_intervals() {
+        let mut set = RangeSet::from_iter([0..10, 20..30]);
+
+        set.remove(3..5);
+        assert_eq!(ranges(&set), "{0..3, 5..10, 20..30}");
+
+        set.remove(8..22);
+        assert_eq!(ranges(&set), "{0..3, 5..8, 22..30}");
+
+        set.remove(0..1);
+        set.remove(29..40);
+        set.remove(12..15);
+        assert_eq!(ranges(&set), "{1..3, 5..8, 22..29}");
+
+        set.remove(-10..100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn split_off() {
+        let mut set = RangeSet::from_iter([0..4, 6..10, 12..15]);
+        let upper = set.split_off(8);
+
+        assert_eq!(ranges(&set), "{0..4, 6..8}");
+        assert_eq!(ranges(&upper), "{8..10, 12..15}");
+
+        let mut set = RangeSet::from_iter([0..4, 6..10]);
+        let upper = set.split_off(5);
+
+        assert_eq!(ranges(&set), "{0..4}");
+        assert_eq!(ranges(&upper), "{6..10}");
+
+        let mut set = RangeSet::from_iter([Interval::new(0, 4)]);
+
+        assert!(set.split_off(4).is_empty());
+        assert_eq!(ranges(&set.split_off(0)), "{0..4}");
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn lookups() {
+        let set = RangeSet::from_iter([0..4, 6..10]);
+
+        assert!(set.contains(3) && !set.contains(4) && set.contains(6));
+        assert_eq!(set.interval_containing(7), Some(Interval::new(6, 10)));
+        assert_eq!(set.interval_containing(5), None);
+        assert!(set.overlaps(3..7) && !set.overlaps(4..6));
+        assert_eq!(
+            set.overlapping(2..8).collect::<Vec<_>>(),
+            [Interval::new(0, 4), Interval::new(6, 10)]
+        );
+        assert_eq!(
+            ranges(&set.intersection(&RangeSet::from_iter([2..7, 9..20]))),
+            "{2..4, 6..7, 9..10}"
+        );
+    }
+
+    #[test]
+    fn gaps() {
+        let set = RangeSet::from_iter([2..4, 6..10, 15..20]);
+
+        assert_eq!(ranges(&set.gaps(0..25)), "{0..2, 4..6, 10..15, 20..25}");
+        assert_eq!(ranges(&set.gaps(3..16)), "{4..6, 10..15}");
+        assert!(set.gaps(6..10).is_empty());
+        assert_eq!(ranges(&RangeSet::new().gaps(1..=3)), "{1..4}");
+    }
+
+    #[test]
+    fn runs() {
+        let runs = [1, 2, 3, 5, 5, 6, 9].into_iter().runs();
+
+        assert_eq!(
+            runs.map(Range::from).collect::<Vec<_>>(),
+            [1..4, 5..7, 9..10]
+        );
+    }
+}
@@ -1,6 +1,23 @@
+use parse::Parser;
 use regex::Captures;
 use std::{collections::BTreeMap, fmt::Debug, str::FromStr, sync::Mutex};
 
+pub mod bits;
+pub mod cycle;
+pub mod diagnostic;
+pub mod dsu;
+pub mod fixture;
+// PNG and GIF export are behind the `image` feature, so that days without them don't
+// build the encoders; PPM and SVG are always available
+pub mod image;
+pub mod interval;
+pub mod memo;
+pub mod num;
+pub mod parse;
+pub mod shape;
+pub mod solution;
+pub mod viz;
+
 #[doc(hidden)]
 pub use lazy_static::lazy_static;
 #[doc(hidden)]
@@ -49,6 +66,8 @@ pub trait StrExt {
     fn usize(&self) -> usize;
     fn char(&self) -> char;
     fn is_match(&self, regex: &str) -> bool;
+    // Parses the whole string with a `parse` combinator, panicking with a diagnostic otherwise
+    fn parse_with<'a, P: Parser<'a>>(&'a self, parser: P) -> P::Output;
 }
 
 impl<S: AsRef<str>> StrExt for S {
@@ -79,6 +98,10 @@ impl<S: AsRef<str>> StrExt for S {
     fn is_match(&self, regex: &str) -> bool {
         self.as_ref().is_match(regex)
     }
+
+    fn parse_with<'a, P: Parser<'a>>(&'a self, parser: P) -> P::Output {
+        self.as_ref().parse_with(parser)
+    }
 }
 
 impl StrExt for str {
@@ -111,6 +134,10 @@ impl StrExt for str {
     fn is_match(&self, regex: &str) -> bool {
         with_cached_regex(regex, |regex| regex.is_match(self.as_ref()))
     }
+
+    fn parse_with<'a, P: Parser<'a>>(&'a self, parser: P) -> P::Output {
+        parser.parse_or_panic(self)
+    }
 }
 
 // TODO: optimize for concurrent access
@@ -240,3 +267,25 @@ where
 
     tuple
 }
+
+pub fn tuple_split_whitespace_parse<T, C>(input: &str) -> C
+where
+    C: TupleExt<T>,
+    T: FromStr,
+    T::Err: Debug,
+{
+    let mut it = input.split_whitespace();
+
+    let tuple = C::from_fn(|| {
+        it.next()
+            .expect("not enough parts")
+            .parse::<T>()
+            .expect("failed to parse part")
+    });
+
+    if it.next().is_some() {
+        panic!("extraneous part")
+    }
+
+    tuple
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Wraps a recursive function so that every top-level call gets its own cache,
+// which is shared by all recursive calls made from within its body.
+//
+// Parameters before the `;` are context (e.g. `&Grid`) and are not part of the cache key.
+// A leading `#[array(len)]` stores results in a fixed-size array indexed by the single key argument,
+// otherwise they are stored in a `HashMap` keyed by all non-context arguments.
+//
+// ```
+// utils::memoize! {
+//     fn fib(n: u64) -> u64 {
+//         if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+//     }
+// }
+// ```
+#[macro_export]
+macro_rules! memoize {
+    (
+        #[array($len:expr)]
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($ctx:ident: $ctx_ty:ty),* ; $arg:ident: $arg_ty:ty $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($ctx: $ctx_ty,)* $arg: $arg_ty) -> $ret {
+            #[allow(unused_mut, unused_variables, clippy::redundant_closure_call)]
+            fn __memoized(
+                cache: &mut $crate::memo::ArrayCache<$ret>,
+                $($ctx: $ctx_ty,)*
+                $arg: $arg_ty,
+            ) -> $ret {
+                let index = ::std::convert::TryInto::<usize>::try_into($arg)
+                    .ok()
+                    .expect("memoization key does not fit in usize");
+
+                assert!(
+                    index < cache.len(),
+                    "memoization key {index} is out of bounds for #[array({})]",
+                    cache.len()
+                );
+
+                if let Some(value) = cache.get(index) {
+                    return ::std::clone::Clone::clone(value);
+                }
+
+                let mut $name = |$arg: $arg_ty| __memoized(cache, $($ctx,)* $arg);
+                let value = (|| -> $ret { $body })();
+
+                cache.insert(index, ::std::clone::Clone::clone(&value));
+
+                value
+            }
+
+            __memoized(&mut $crate::memo::ArrayCache::new($len), $($ctx,)* $arg)
+        }
+    };
+    (
+        #[array($len:expr)]
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($arg:ident: $arg_ty:ty $(,)?) -> $ret:ty $body:block
+    ) => {
+        $crate::memoize! {
+            #[array($len)]
+            $(#[$attr])*
+            $vis fn $name(; $arg: $arg_ty) -> $ret $body
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($ctx:ident: $ctx_ty:ty),* ; $($arg:ident: $arg_ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($ctx: $ctx_ty,)* $($arg: $arg_ty),*) -> $ret {
+            #[allow(unused_mut, unused_variables, clippy::redundant_closure_call)]
+            fn __memoized(
+                cache: &mut $crate::memo::HashCache<($($arg_ty,)*), $ret>,
+                $($ctx: $ctx_ty,)*
+                $($arg: $arg_ty),*
+            ) -> $ret {
+                let key = ($(::std::clone::Clone::clone(&$arg),)*);
+
+                if let Some(value) = cache.get(&key) {
+                    return ::std::clone::Clone::clone(value);
+                }
+
+                let mut $name = |$($arg: $arg_ty),*| __memoized(cache, $($ctx,)* $($arg),*);
+                let value = (|| -> $ret { $body })();
+
+                cache.insert(key, ::std::clone::Clone::clone(&value));
+
+                value
+            }
+
+            __memoized(&mut $crate::memo::HashCache::default(), $($ctx,)* $($arg),*)
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $crate::memoize! {
+            $(#[$attr])*
+            $vis fn $name(; $($arg: $arg_ty),*) -> $ret $body
+        }
+    };
+}
+
+pub struct HashCache<K, V> {
+    values: HashMap<K, V>,
+}
+
+impl<K, V> Default for HashCache<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> HashCache<K, V> {
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.values.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.values.insert(key, value);
+    }
+}
+
+pub struct ArrayCache<V> {
+    values: Box<[Option<V>]>,
+}
+
+impl<V> ArrayCache<V> {
+    pub fn new(len: usize) -> Self {
+        Self {
+            values: (0..len).map(|_| None).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&V> {
+        self.values.get(index)?.as_ref()
+    }
+
+    pub fn insert(&mut self, index: usize, value: V) {
+        self.values[index] = Some(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    crate::memoize! {
+        // Monotonic lattice paths to `(x, y)`, counting the evaluated bodies in `calls`
+        fn paths(calls: &Cell<u32>; x: u32, y: u32) -> u64 {
+            calls.set(calls.get() + 1);
+
+            match (x, y) {
+                (0, _) | (_, 0) => 1,
+                _ => paths(x - 1, y) + paths(x, y - 1),
+            }
+        }
+    }
+
+    crate::memoize! {
+        fn words(n: usize) -> Vec<String> {
+            if n == 0 {
+                return vec![String::new()];
+            }
+
+            words(n - 1)
+                .into_iter()
+                .flat_map(|word| [format!("{word}a"), format!("{word}b")])
+                .collect()
+        }
+    }
+
+    crate::memoize! {
+        #[array(91)]
+        fn fib(n: usize) -> u64 {
+            if n < 2 {
+                n as u64
+            } else {
+                fib(n - 1) + fib(n - 2)
+            }
+        }
+    }
+
+    crate::memoize! {
+        #[array(32)]
+        fn climbs(steps: &[usize], calls: &Cell<u32>; n: usize) -> u64 {
+            calls.set(calls.get() + 1);
+
+            if n == 0 {
+                return 1;
+            }
+
+            steps
+                .iter()
+                .filter(|&&step| step <= n)
+                .map(|&step| climbs(n - step))
+                .sum()
+        }
+    }
+
+    #[test]
+    fn hash_cache_evaluates_each_key_once() {
+        let calls = Cell::new(0);
+
+        // Every point of the grid is reached, except for the origin
+        assert_eq!(paths(&calls, 16, 16), 601_080_390);
+        assert_eq!(calls.get(), 17 * 17 - 1);
+
+        // Every top-level call starts with an empty cache
+        calls.set(0);
+
+        assert_eq!(paths(&calls, 2, 2), 6);
+        assert_eq!(calls.get(), 8);
+        assert_eq!(words(2), ["aa", "ab", "ba", "bb"]);
+        assert_eq!(words(16).len(), 1 << 16);
+    }
+
+    #[test]
+    fn array_cache_evaluates_each_key_once() {
+        let calls = Cell::new(0);
+
+        assert_eq!(fib(0), 0);
+        assert_eq!(fib(90), 2_880_067_194_370_816_120);
+        assert_eq!(climbs(&[1, 2, 3], &calls, 31), 98_950_096);
+        assert_eq!(calls.get(), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "memoization key 91 is out of bounds for #[array(91)]")]
+    fn array_cache_rejects_keys_beyond_its_length() {
+        fib(91);
+    }
+}
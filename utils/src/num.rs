@@ -0,0 +1,290 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Eq
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+
+    fn abs(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn isqrt(self) -> Self;
+}
+
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    (unsigned: $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TEN: Self = 10;
+
+            fn abs(self) -> Self {
+                self
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn isqrt(self) -> Self {
+                <$t>::isqrt(self)
+            }
+        }
+    )*};
+    (signed: $($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TEN: Self = 10;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+
+            fn isqrt(self) -> Self {
+                <$t>::isqrt(self)
+            }
+        }
+
+        impl SignedInteger for $t {}
+    )*};
+}
+
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+
+pub fn num_digits<T: Integer>(mut n: T) -> u32 {
+    let mut digits = 1;
+
+    while n / T::TEN != T::ZERO {
+        n = n / T::TEN;
+        digits += 1;
+    }
+
+    digits
+}
+
+pub fn pow10<T: Integer>(exp: u32) -> T {
+    (0..exp).fold(T::ONE, |n, _| n * T::TEN)
+}
+
+// Splits off the lowest `digits` digits, e.g. `split_digits(1234, 2) == (12, 34)`
+pub fn split_digits<T: Integer>(n: T, digits: u32) -> (T, T) {
+    let divisor = pow10::<T>(digits);
+
+    (n / divisor, n % divisor)
+}
+
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+
+    (a / gcd(a, b) * b).abs()
+}
+
+// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`
+pub fn ext_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        return (-old_r, -old_x, -old_y);
+    }
+
+    (old_r, old_x, old_y)
+}
+
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = ext_gcd(a.rem_euclid(modulus), modulus);
+
+    if g != T::ONE {
+        return None;
+    }
+
+    Some(x.rem_euclid(modulus))
+}
+
+// Combines congruences `x ≡ residue (mod modulus)` into a single `(residue, modulus)`.
+// The moduli do not need to be coprime; `None` means the congruences contradict each other.
+pub fn crt<T: SignedInteger>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut combined = (T::ZERO, T::ONE);
+
+    for &(residue, modulus) in congruences {
+        let (combined_residue, combined_modulus) = combined;
+        let (g, x, _) = ext_gcd(combined_modulus, modulus);
+        let difference = residue - combined_residue;
+
+        if difference % g != T::ZERO {
+            return None;
+        }
+
+        let lcm = combined_modulus / g * modulus;
+        let step = (difference / g * x).rem_euclid(modulus / g);
+        let residue = (combined_residue + combined_modulus * step).rem_euclid(lcm);
+
+        combined = (residue, lcm);
+    }
+
+    Some(combined)
+}
+
+// The solution `(x, y)` of `a * x + b * y == e` and `c * x + d * y == f` by Cramer's rule.
+// `None` if it isn't integral, or if there isn't exactly one (`a * d == b * c`).
+pub fn solve_2x2<T: SignedInteger>(
+    [[a, b], [c, d]]: [[T; 2]; 2],
+    [e, f]: [T; 2],
+) -> Option<(T, T)> {
+    let determinant = a * d - b * c;
+
+    if determinant == T::ZERO {
+        return None;
+    }
+
+    let x = e * d - b * f;
+    let y = a * f - c * e;
+
+    if x % determinant != T::ZERO || y % determinant != T::ZERO {
+        return None;
+    }
+
+    Some((x / determinant, y / determinant))
+}
+
+pub fn modulo<T: Integer>(a: T, modulus: T) -> T {
+    a.rem_euclid(modulus)
+}
+
+pub fn isqrt<T: Integer>(n: T) -> T {
+    n.isqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits() {
+        assert_eq!(num_digits(0u32), 1);
+        assert_eq!(num_digits(9u8), 1);
+        assert_eq!(num_digits(10u64), 2);
+        assert_eq!(num_digits(99_999i32), 5);
+        assert_eq!(num_digits(-1234i64), 4);
+        assert_eq!(num_digits(u64::MAX), 20);
+        assert_eq!(pow10::<u64>(0), 1);
+        assert_eq!(pow10::<u64>(19), 10_000_000_000_000_000_000);
+        assert_eq!(split_digits(1234u32, 2), (12, 34));
+        assert_eq!(split_digits(1000u32, 2), (10, 0));
+        assert_eq!(split_digits(253_000u64, 3), (253, 0));
+        assert_eq!(split_digits(7u32, 0), (7, 0));
+    }
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(gcd(0i32, -5), 5);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(-4i64, 6), 12);
+        assert_eq!(lcm(0u64, 6), 0);
+        assert_eq!(lcm(101i64, 103), 10403);
+    }
+
+    #[test]
+    fn ext_gcd_with_negative_inputs() {
+        for (a, b) in [
+            (240i64, 46),
+            (-240, 46),
+            (240, -46),
+            (-240, -46),
+            (0, -7),
+            (7, 0),
+        ] {
+            let (g, x, y) = ext_gcd(a, b);
+
+            assert_eq!(g, gcd(a, b), "gcd of {a} and {b}");
+            assert_eq!(a * x + b * y, g, "coefficients for {a} and {b}");
+        }
+    }
+
+    #[test]
+    fn mod_inverses() {
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(14i64, 11), Some(4));
+        assert_eq!(mod_inverse(6i64, 9), None);
+        assert_eq!(mod_inverse(0i64, 7), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(-1i64, 101), (-1, 103)]), Some((10402, 10403)));
+        assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+
+        // Moduli that share a factor combine into their lcm, or contradict each other
+        assert_eq!(crt(&[(3i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(2i64, 4), (4, 8), (6, 10)]), None);
+    }
+
+    #[test]
+    fn linear_systems() {
+        // The first claw machine of 2024 day 13
+        assert_eq!(
+            solve_2x2([[94i64, 22], [34, 67]], [8400, 5400]),
+            Some((80, 40))
+        );
+        assert_eq!(solve_2x2([[1i64, 1], [1, -1]], [-2, 4]), Some((1, -3)));
+
+        // Not integral
+        assert_eq!(solve_2x2([[2i64, 0], [0, 2]], [3, 4]), None);
+
+        // Parallel buttons, with no solution or infinitely many
+        assert_eq!(solve_2x2([[1i64, 2], [2, 4]], [3, 7]), None);
+        assert_eq!(solve_2x2([[1i64, 2], [2, 4]], [3, 6]), None);
+    }
+
+    #[test]
+    fn modulo_and_isqrt() {
+        assert_eq!(modulo(-1i32, 101), 100);
+        assert_eq!(modulo(205i32, 101), 3);
+        assert_eq!(isqrt(99u32), 9);
+        assert_eq!(isqrt(100u32), 10);
+    }
+}
@@ -0,0 +1,891 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+use crate::diagnostic::Diagnostic;
+
+// Small parser combinators over `&str`. Outputs borrow from the input wherever possible.
+//
+// ```ignore
+// let button = |label| (label, "X+", uint(), ", Y+", uint()).map(|(_, _, x, _, y)| Vec2 { x, y });
+// let prize = ("Prize: X=", uint(), ", Y=", uint()).map(|(_, x, _, y)| Vec2 { x, y });
+// let machine = (button("Button A: "), "\n", button("Button B: "), "\n", prize)
+//     .map(|(a, _, b, _, prize)| Machine { a, b, prize });
+//
+// let machines = input.parse_with(sections(machine));
+// ```
+//
+// String literals and chars parse themselves, tuples parse their parsers in sequence,
+// and functions from `&str` to `Result<(T, &str), Failure>` are parsers as well.
+pub trait Parser<'a> {
+    type Output;
+
+    // Parses the start of `input`, returning the output and the rest of the input
+    fn parse_prefix(&self, input: &'a str) -> Result<(Self::Output, &'a str), Failure>;
+
+    // Like `parse_prefix`, but also returns the deepest failure that was backtracked
+    // from on the way, e.g. the missing item after a separator that `separated` gave back.
+    // Combinators pass it on, so that `parse_all` can report it if the input isn't used up.
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, Self::Output> {
+        self.parse_prefix(input)
+            .map(|(output, rest)| (output, rest, None))
+    }
+
+    // Parses all of `input`; trailing line breaks are allowed
+    fn parse_all(&self, input: &'a str) -> Result<Self::Output, Diagnostic> {
+        let failure = match self.parse_recovering(input) {
+            Ok((output, rest, recovered)) => {
+                let rest = rest.trim_start_matches(['\n', '\r']);
+
+                if rest.is_empty() {
+                    return Ok(output);
+                }
+
+                deepest(recovered, Some(Failure::new(rest, "end of input"))).unwrap()
+            }
+            Err(failure) => failure,
+        };
+
+        let offset = input.len() - failure.rest;
+        let message = match input[offset..].chars().next() {
+            Some(ch) => format!("expected {}, found {ch:?}", failure.expected),
+            None => format!("expected {}, found end of input", failure.expected),
+        };
+
+        Err(Diagnostic::new(input, offset, 1, message))
+    }
+
+    fn parse_or_panic(&self, input: &'a str) -> Self::Output {
+        self.parse_all(input)
+            .unwrap_or_else(|diagnostic| panic!("failed to parse input\n{diagnostic}"))
+    }
+
+    fn map<T, F>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> T,
+    {
+        Map { parser: self, f }
+    }
+
+    fn or<P>(self, other: P) -> Or<Self, P>
+    where
+        Self: Sized,
+        P: Parser<'a, Output = Self::Output>,
+    {
+        Or(self, other)
+    }
+
+    fn optional(self) -> Optional<Self>
+    where
+        Self: Sized,
+    {
+        Optional(self)
+    }
+
+    fn then<P: Parser<'a>>(self, next: P) -> (Self, P)
+    where
+        Self: Sized,
+    {
+        (self, next)
+    }
+
+    fn then_ignore<P: Parser<'a>>(self, next: P) -> ThenIgnore<Self, P>
+    where
+        Self: Sized,
+    {
+        ThenIgnore(self, next)
+    }
+
+    fn ignore_then<P: Parser<'a>>(self, next: P) -> IgnoreThen<Self, P>
+    where
+        Self: Sized,
+    {
+        IgnoreThen(self, next)
+    }
+
+    fn separated_by<S: Parser<'a>>(self, separator: S) -> SeparatedBy<Self, S>
+    where
+        Self: Sized,
+    {
+        SeparatedBy {
+            item: self,
+            separator,
+        }
+    }
+
+    // Replaces what was expected when nothing could be parsed at all
+    fn label(self, label: &'static str) -> Label<Self>
+    where
+        Self: Sized,
+    {
+        Label {
+            parser: self,
+            label,
+        }
+    }
+}
+
+// The output, the rest of the input and the deepest failure backtracked from
+pub type Recovering<'a, T> = Result<(T, &'a str, Option<Failure>), Failure>;
+
+// Where parsing failed, as the length of the input left at that point, and what
+// was expected there. The deepest failure wins when alternatives are merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    rest: usize,
+    expected: Expected,
+}
+
+impl Failure {
+    pub fn new(rest: &str, expected: &'static str) -> Self {
+        Self {
+            rest: rest.len(),
+            expected: Expected::Named(expected),
+        }
+    }
+
+    fn literal(rest: &str, literal: &'static str) -> Self {
+        Self {
+            rest: rest.len(),
+            expected: Expected::Literal(literal),
+        }
+    }
+
+    fn merge(self, other: Failure) -> Failure {
+        if self.rest != other.rest {
+            return if self.rest < other.rest { self } else { other };
+        }
+
+        let mut expected = Vec::new();
+
+        for failure in [self.expected, other.expected] {
+            match failure {
+                Expected::OneOf(alternatives) => expected.extend(alternatives),
+                failure => expected.push(failure),
+            }
+        }
+
+        Failure {
+            rest: self.rest,
+            expected: Expected::OneOf(expected),
+        }
+    }
+
+    // Whether the parser gave up before consuming any of `input`
+    fn is_at(&self, input: &str) -> bool {
+        self.rest == input.len()
+    }
+}
+
+fn deepest(a: Option<Failure>, b: Option<Failure>) -> Option<Failure> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.merge(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn without_recovered<'a, T>(result: Recovering<'a, T>) -> Result<(T, &'a str), Failure> {
+    result.map(|(output, rest, _)| (output, rest))
+}
+
+// Runs the next parser of a sequence. What earlier parsers backtracked from is kept
+// in `recovered`, and merged into the failure if this one fails.
+fn step<'a, P: Parser<'a>>(
+    parser: &P,
+    input: &'a str,
+    recovered: &mut Option<Failure>,
+) -> Result<(P::Output, &'a str), Failure> {
+    match parser.parse_recovering(input) {
+        Ok((output, rest, failure)) => {
+            *recovered = deepest(recovered.take(), failure);
+            Ok((output, rest))
+        }
+        Err(failure) => Err(deepest(recovered.take(), Some(failure)).unwrap()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expected {
+    Char(char),
+    Literal(&'static str),
+    Named(&'static str),
+    OneOf(Vec<Expected>),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Char(ch) => write!(f, "{ch:?}"),
+            Expected::Literal(literal) => write!(f, "{literal:?}"),
+            Expected::Named(name) => f.write_str(name),
+            Expected::OneOf(alternatives) => {
+                for (index, alternative) in alternatives.iter().enumerate() {
+                    match index {
+                        0 => {}
+                        index if index == alternatives.len() - 1 => f.write_str(" or ")?,
+                        _ => f.write_str(", ")?,
+                    }
+
+                    write!(f, "{alternative}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a> for F
+where
+    F: Fn(&'a str) -> Result<(T, &'a str), Failure>,
+{
+    type Output = T;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(T, &'a str), Failure> {
+        self(input)
+    }
+}
+
+impl<'a> Parser<'a> for &'static str {
+    type Output = &'a str;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(&'a str, &'a str), Failure> {
+        match input.strip_prefix(*self) {
+            Some(rest) => Ok((&input[..self.len()], rest)),
+            None => Err(Failure::literal(input, self)),
+        }
+    }
+}
+
+impl<'a> Parser<'a> for char {
+    type Output = char;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(char, &'a str), Failure> {
+        match input.strip_prefix(*self) {
+            Some(rest) => Ok((*self, rest)),
+            None => Err(Failure {
+                rest: input.len(),
+                expected: Expected::Char(*self),
+            }),
+        }
+    }
+}
+
+macro_rules! impl_sequence {
+    ($($parser:ident $output:ident),+) => {
+        impl<'a, $($parser: Parser<'a>),+> Parser<'a> for ($($parser,)+) {
+            type Output = ($($parser::Output,)+);
+
+            fn parse_prefix(&self, input: &'a str) -> Result<(Self::Output, &'a str), Failure> {
+                without_recovered(self.parse_recovering(input))
+            }
+
+            #[allow(non_snake_case)]
+            fn parse_recovering(&self, input: &'a str) -> Recovering<'a, Self::Output> {
+                let ($($parser,)+) = self;
+                let rest = input;
+                let mut recovered = None;
+                $(let ($output, rest) = step($parser, rest, &mut recovered)?;)+
+
+                Ok((($($output,)+), rest, recovered))
+            }
+        }
+    };
+}
+
+impl_sequence!(A a);
+impl_sequence!(A a, B b);
+impl_sequence!(A a, B b, C c);
+impl_sequence!(A a, B b, C c, D d);
+impl_sequence!(A a, B b, C c, D d, E e);
+impl_sequence!(A a, B b, C c, D d, E e, F f);
+impl_sequence!(A a, B b, C c, D d, E e, F f, G g);
+impl_sequence!(A a, B b, C c, D d, E e, F f, G g, H h);
+
+pub fn literal(literal: &'static str) -> &'static str {
+    literal
+}
+
+pub struct Uint<T>(PhantomData<fn() -> T>);
+
+// Decimal digits without a sign
+pub fn uint<T: FromStr>() -> Uint<T> {
+    Uint(PhantomData)
+}
+
+impl<'a, T: FromStr> Parser<'a> for Uint<T> {
+    type Output = T;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(T, &'a str), Failure> {
+        let len = input.len()
+            - input
+                .trim_start_matches(|ch: char| ch.is_ascii_digit())
+                .len();
+        let (digits, rest) = input.split_at(len);
+
+        match digits.parse() {
+            Ok(n) if len > 0 => Ok((n, rest)),
+            _ => Err(Failure::new(input, "an unsigned integer")),
+        }
+    }
+}
+
+pub struct Int<T>(PhantomData<fn() -> T>);
+
+// Decimal digits with an optional `-` or `+`
+pub fn int<T: FromStr>() -> Int<T> {
+    Int(PhantomData)
+}
+
+impl<'a, T: FromStr> Parser<'a> for Int<T> {
+    type Output = T;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(T, &'a str), Failure> {
+        let unsigned = input.strip_prefix(['-', '+']).unwrap_or(input);
+        let digits = unsigned.len()
+            - unsigned
+                .trim_start_matches(|ch: char| ch.is_ascii_digit())
+                .len();
+        let (number, rest) = input.split_at(input.len() - unsigned.len() + digits);
+
+        match number.parse() {
+            Ok(n) if digits > 0 => Ok((n, rest)),
+            _ => Err(Failure::new(input, "an integer")),
+        }
+    }
+}
+
+pub struct TakeWhile<F> {
+    predicate: F,
+    min: usize,
+}
+
+// The longest prefix of chars matching `predicate`, possibly empty
+pub fn take_while<F: Fn(char) -> bool>(predicate: F) -> TakeWhile<F> {
+    TakeWhile { predicate, min: 0 }
+}
+
+// Like `take_while`, but at least one char has to match
+pub fn take_while1<F: Fn(char) -> bool>(predicate: F) -> TakeWhile<F> {
+    TakeWhile { predicate, min: 1 }
+}
+
+impl<'a, F: Fn(char) -> bool> Parser<'a> for TakeWhile<F> {
+    type Output = &'a str;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(&'a str, &'a str), Failure> {
+        let rest = input.trim_start_matches(&self.predicate);
+        let taken = &input[..input.len() - rest.len()];
+
+        if taken.chars().count() < self.min {
+            return Err(Failure::new(input, "a matching character"));
+        }
+
+        Ok((taken, rest))
+    }
+}
+
+// Zero or more spaces
+pub fn spaces<'a>() -> impl Parser<'a, Output = &'a str> {
+    take_while(|ch| ch == ' ')
+}
+
+pub struct RegexMatch(&'static Regex);
+
+// A match of the regex at the start of the input, e.g. `regex(re!(r"^[a-z]+"))`.
+// The regex has to start with `^`, so that a failed attempt doesn't search the rest
+// of the input, which would make `or` and `separated` quadratic.
+pub fn regex(regex: &'static Regex) -> RegexMatch {
+    assert_anchored(regex);
+    RegexMatch(regex)
+}
+
+fn assert_anchored(regex: &Regex) {
+    assert!(
+        regex.as_str().starts_with('^'),
+        "regex parsers need a pattern starting with `^`, got /{regex}/"
+    );
+}
+
+impl<'a> Parser<'a> for RegexMatch {
+    type Output = &'a str;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(&'a str, &'a str), Failure> {
+        match self.0.find(input) {
+            Some(found) => Ok(input.split_at(found.end())),
+            None => Err(Failure::new(input, "a match of a regex")),
+        }
+    }
+}
+
+pub struct RegexCaptures(&'static Regex);
+
+// Like `regex`, but yields the captures, to be read with `utils::CapturesExt`:
+//
+// ```ignore
+// let point = captures(re!(r"^(?<x>-?\d+),(?<y>-?\d+)"));
+//
+// point.map(|caps| (caps.parse::<i32>("x"), caps.parse::<i32>("y")))
+// ```
+pub fn captures(regex: &'static Regex) -> RegexCaptures {
+    assert_anchored(regex);
+    RegexCaptures(regex)
+}
+
+impl<'a> Parser<'a> for RegexCaptures {
+    type Output = Captures<'a>;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(Captures<'a>, &'a str), Failure> {
+        match self.0.captures(input) {
+            Some(captures) => {
+                let end = captures.get(0).unwrap().end();
+
+                Ok((captures, &input[end..]))
+            }
+            None => Err(Failure::new(input, "a match of a regex")),
+        }
+    }
+}
+
+pub struct Map<P, F> {
+    parser: P,
+    f: F,
+}
+
+impl<'a, P: Parser<'a>, T, F: Fn(P::Output) -> T> Parser<'a> for Map<P, F> {
+    type Output = T;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(T, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, T> {
+        let (output, rest, recovered) = self.parser.parse_recovering(input)?;
+
+        Ok(((self.f)(output), rest, recovered))
+    }
+}
+
+pub struct Or<A, B>(A, B);
+
+impl<'a, A: Parser<'a>, B: Parser<'a, Output = A::Output>> Parser<'a> for Or<A, B> {
+    type Output = A::Output;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(A::Output, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, A::Output> {
+        self.0
+            .parse_recovering(input)
+            .or_else(|first| match self.1.parse_recovering(input) {
+                Ok((output, rest, recovered)) => {
+                    Ok((output, rest, deepest(Some(first), recovered)))
+                }
+                Err(second) => Err(first.merge(second)),
+            })
+    }
+}
+
+pub struct Optional<P>(P);
+
+// `None` if `parser` fails right at the start. Like in `separated`, a failure after
+// consuming some input is passed on, so that it's reported where it happened.
+pub fn optional<'a, P: Parser<'a>>(parser: P) -> Optional<P> {
+    Optional(parser)
+}
+
+impl<'a, P: Parser<'a>> Parser<'a> for Optional<P> {
+    type Output = Option<P::Output>;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(Option<P::Output>, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, Option<P::Output>> {
+        match self.0.parse_recovering(input) {
+            Ok((output, rest, recovered)) => Ok((Some(output), rest, recovered)),
+            Err(failure) if failure.is_at(input) => Ok((None, input, Some(failure))),
+            Err(failure) => Err(failure),
+        }
+    }
+}
+
+pub struct ThenIgnore<A, B>(A, B);
+
+impl<'a, A: Parser<'a>, B: Parser<'a>> Parser<'a> for ThenIgnore<A, B> {
+    type Output = A::Output;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(A::Output, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, A::Output> {
+        let mut recovered = None;
+        let (output, rest) = step(&self.0, input, &mut recovered)?;
+        let (_, rest) = step(&self.1, rest, &mut recovered)?;
+
+        Ok((output, rest, recovered))
+    }
+}
+
+pub struct IgnoreThen<A, B>(A, B);
+
+impl<'a, A: Parser<'a>, B: Parser<'a>> Parser<'a> for IgnoreThen<A, B> {
+    type Output = B::Output;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(B::Output, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, B::Output> {
+        let mut recovered = None;
+        let (_, rest) = step(&self.0, input, &mut recovered)?;
+        let (output, rest) = step(&self.1, rest, &mut recovered)?;
+
+        Ok((output, rest, recovered))
+    }
+}
+
+pub struct SeparatedBy<P, S> {
+    item: P,
+    separator: S,
+}
+
+// One or more `item`s. A separator is given back if no item follows it, so that
+// `separated(int(), ",")` stops before a trailing comma; if the input doesn't continue
+// in a way that fits, `parse_all` reports the missing item after the comma. An item
+// that fails after consuming some input fails the whole list right away.
+pub fn separated<'a, P: Parser<'a>, S: Parser<'a>>(item: P, separator: S) -> SeparatedBy<P, S> {
+    item.separated_by(separator)
+}
+
+// Items on consecutive lines
+pub fn lines<'a, P: Parser<'a>>(item: P) -> SeparatedBy<P, &'static str> {
+    separated(item, "\n")
+}
+
+// Items separated by blank lines
+pub fn sections<'a, P: Parser<'a>>(item: P) -> SeparatedBy<P, &'static str> {
+    separated(item, "\n\n")
+}
+
+impl<'a, P: Parser<'a>, S: Parser<'a>> Parser<'a> for SeparatedBy<P, S> {
+    type Output = Vec<P::Output>;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(Vec<P::Output>, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, Vec<P::Output>> {
+        let (first, mut rest, mut recovered) = self.item.parse_recovering(input)?;
+        let mut items = vec![first];
+
+        while let Ok((_, after_separator)) = self.separator.parse_prefix(rest) {
+            match self.item.parse_recovering(after_separator) {
+                Ok((item, after_item, failure)) => {
+                    items.push(item);
+                    rest = after_item;
+                    recovered = deepest(recovered, failure);
+                }
+                Err(failure) if failure.is_at(after_separator) => {
+                    recovered = deepest(recovered, Some(failure));
+                    break;
+                }
+                Err(failure) => return Err(deepest(recovered, Some(failure)).unwrap()),
+            }
+        }
+
+        Ok((items, rest, recovered))
+    }
+}
+
+pub struct Label<P> {
+    parser: P,
+    label: &'static str,
+}
+
+impl<'a, P: Parser<'a>> Parser<'a> for Label<P> {
+    type Output = P::Output;
+
+    fn parse_prefix(&self, input: &'a str) -> Result<(P::Output, &'a str), Failure> {
+        without_recovered(self.parse_recovering(input))
+    }
+
+    fn parse_recovering(&self, input: &'a str) -> Recovering<'a, P::Output> {
+        self.parser.parse_recovering(input).map_err(|failure| {
+            if failure.is_at(input) {
+                Failure::new(input, self.label)
+            } else {
+                failure
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::re;
+
+    // Line, column and message of the diagnostic for `input`
+    fn error<'a, P: Parser<'a>>(parser: P, input: &'a str) -> (usize, usize, String) {
+        match parser.parse_all(input) {
+            Ok(_) => panic!("{input:?} was parsed"),
+            Err(diagnostic) => (diagnostic.line, diagnostic.column, diagnostic.message),
+        }
+    }
+
+    fn digit(input: &str) -> Result<(u32, &str), Failure> {
+        match input.chars().next().and_then(|ch| ch.to_digit(10)) {
+            Some(digit) => Ok((digit, &input[1..])),
+            None => Err(Failure::new(input, "a digit")),
+        }
+    }
+
+    #[test]
+    fn literals_and_chars() {
+        assert_eq!("ab".parse_prefix("abc"), Ok(("ab", "c")));
+        assert_eq!(literal("ab").parse_prefix("abc"), Ok(("ab", "c")));
+        assert_eq!('a'.parse_prefix("abc"), Ok(('a', "bc")));
+        assert_eq!(
+            error("ab", "ax"),
+            (1, 1, r#"expected "ab", found 'a'"#.to_string())
+        );
+        assert_eq!(
+            error('b', "a"),
+            (1, 1, "expected 'b', found 'a'".to_string())
+        );
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(digit.parse_prefix("7x"), Ok((7, "x")));
+        assert_eq!(
+            error(digit, "x"),
+            (1, 1, "expected a digit, found 'x'".to_string())
+        );
+    }
+
+    #[test]
+    fn sequences() {
+        let point = (int::<i32>(), ',', int::<i32>());
+
+        assert_eq!(point.parse_all("3,-4"), Ok((3, ',', -4)));
+        assert_eq!(('a',).parse_all("a"), Ok(('a',)));
+        assert_eq!(
+            error(point, "3;4"),
+            (1, 2, "expected ',', found ';'".to_string())
+        );
+        assert_eq!('a'.then('b').parse_all("ab"), Ok(('a', 'b')));
+    }
+
+    #[test]
+    fn unsigned_integers() {
+        assert_eq!(uint::<u32>().parse_prefix("42x"), Ok((42, "x")));
+        assert_eq!(uint::<u8>().parse_all("255"), Ok(255));
+        assert!(uint::<u32>().parse_prefix("-1").is_err());
+        assert!(uint::<u32>().parse_prefix("").is_err());
+        assert_eq!(
+            error(uint::<u8>(), "256"),
+            (1, 1, "expected an unsigned integer, found '2'".to_string())
+        );
+    }
+
+    #[test]
+    fn signed_integers() {
+        assert_eq!(int::<i32>().parse_prefix("-12,"), Ok((-12, ",")));
+        assert_eq!(int::<i32>().parse_prefix("+12"), Ok((12, "")));
+        assert_eq!(int::<i8>().parse_all("-128"), Ok(-128));
+        assert!(int::<i32>().parse_prefix("-").is_err());
+        assert!(int::<i32>().parse_prefix("--1").is_err());
+        assert_eq!(
+            error(int::<i8>(), "x\n128"),
+            (1, 1, "expected an integer, found 'x'".to_string())
+        );
+        assert_eq!(
+            error(("x\n", int::<i8>()), "x\n128"),
+            (2, 1, "expected an integer, found '1'".to_string())
+        );
+    }
+
+    #[test]
+    fn take_while_and_spaces() {
+        let word = take_while(|ch: char| ch.is_ascii_lowercase());
+
+        assert_eq!(word.parse_prefix("ab1"), Ok(("ab", "1")));
+        assert_eq!(word.parse_prefix("1"), Ok(("", "1")));
+        assert!(take_while1(|ch: char| ch.is_ascii_lowercase())
+            .parse_prefix("1")
+            .is_err());
+        assert_eq!(spaces().parse_prefix("  x"), Ok(("  ", "x")));
+    }
+
+    #[test]
+    fn regexes() {
+        assert_eq!(regex(re!(r"^[a-z]+")).parse_prefix("ab1"), Ok(("ab", "1")));
+        assert!(regex(re!(r"^[a-z]+")).parse_prefix("1ab").is_err());
+
+        let point = captures(re!(r"^(?<x>\d+),(?<y>\d+)"));
+        let (caps, rest) = point.parse_prefix("1,2 rest").unwrap();
+
+        assert_eq!((&caps["x"], &caps["y"], rest), ("1", "2", " rest"));
+    }
+
+    #[test]
+    #[should_panic(expected = "starting with `^`")]
+    fn unanchored_regexes() {
+        regex(re!(r"[a-z]+"));
+    }
+
+    #[test]
+    fn map_and_ignore() {
+        assert_eq!(uint::<u32>().map(|n| n * 2).parse_all("21"), Ok(42));
+        assert_eq!('('.ignore_then(uint::<u32>()).parse_all("(1"), Ok(1));
+        assert_eq!(uint::<u32>().then_ignore(')').parse_all("1)"), Ok(1));
+        assert_eq!(
+            error(uint::<u32>().then_ignore(')'), "1]"),
+            (1, 2, "expected ')', found ']'".to_string())
+        );
+    }
+
+    #[test]
+    fn or_merges_failures() {
+        let sign = '-'.or('+');
+
+        assert_eq!(sign.parse_all("+"), Ok('+'));
+        assert_eq!(
+            error(sign, "x"),
+            (1, 1, "expected '-' or '+', found 'x'".to_string())
+        );
+        assert_eq!(
+            error('a'.or('b').or('c'), "x"),
+            (1, 1, "expected 'a', 'b' or 'c', found 'x'".to_string())
+        );
+
+        // The alternative that got further wins
+        let pair = ('a', 'b').map(|_| ()).or('a'.map(|_| ()).then_ignore('c'));
+
+        assert_eq!(
+            error(("x", pair), "xax"),
+            (1, 3, "expected 'b' or 'c', found 'x'".to_string())
+        );
+    }
+
+    #[test]
+    fn optional_backtracks_only_at_the_start() {
+        let number = (optional('-'), uint::<u32>());
+
+        assert_eq!(number.parse_all("-1"), Ok((Some('-'), 1)));
+        assert_eq!(number.parse_all("1"), Ok((None, 1)));
+        assert_eq!(
+            error(number, "x"),
+            (
+                1,
+                1,
+                "expected '-' or an unsigned integer, found 'x'".to_string()
+            )
+        );
+        assert_eq!(
+            error((optional(("x", uint::<u8>())), "y"), "x300y"),
+            (1, 2, "expected an unsigned integer, found '3'".to_string())
+        );
+        assert_eq!('a'.optional().parse_prefix("b"), Ok((None, "b")));
+    }
+
+    #[test]
+    fn separated_gives_back_a_trailing_separator() {
+        let list = || separated(uint::<u32>(), ',');
+
+        assert_eq!(list().parse_prefix("1,2,3"), Ok((vec![1, 2, 3], "")));
+        assert_eq!(list().parse_prefix("1,2,;"), Ok((vec![1, 2], ",;")));
+        assert_eq!(list().then_ignore(",;").parse_all("1,2,;"), Ok(vec![1, 2]));
+        assert_eq!(
+            error(list(), "1,2,;"),
+            (1, 5, "expected an unsigned integer, found ';'".to_string())
+        );
+        assert_eq!(
+            error(list(), ""),
+            (
+                1,
+                1,
+                "expected an unsigned integer, found end of input".to_string()
+            )
+        );
+        assert_eq!(
+            error(lines(list()), "1,2,3,\n4,5"),
+            (
+                1,
+                7,
+                "expected an unsigned integer, found '\\n'".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn separated_fails_on_items_that_consumed_input() {
+        let pairs = separated(('(', uint::<u32>(), ')'), ' ');
+
+        assert_eq!(
+            error(pairs, "(1) (2]"),
+            (1, 7, "expected ')', found ']'".to_string())
+        );
+    }
+
+    #[test]
+    fn lines_and_sections() {
+        let numbers = || lines(uint::<u32>());
+
+        assert_eq!(numbers().parse_all("1\n2\n\n"), Ok(vec![1, 2]));
+        assert_eq!(
+            sections(numbers()).parse_all("1\n2\n\n3\n"),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            error(numbers(), "1\n2\nx"),
+            (
+                3,
+                1,
+                "expected an unsigned integer or end of input, found 'x'".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let coordinate = || (uint::<u32>(), ',', uint::<u32>()).label("a coordinate");
+
+        assert_eq!(
+            error(coordinate(), "x"),
+            (1, 1, "expected a coordinate, found 'x'".to_string())
+        );
+        // Failures after some input was consumed are more precise than the label
+        assert_eq!(
+            error(coordinate(), "1;2"),
+            (1, 2, "expected ',', found ';'".to_string())
+        );
+    }
+
+    #[test]
+    fn trailing_input() {
+        assert_eq!(
+            error(uint::<u32>(), "12\n3"),
+            (2, 1, "expected end of input, found '3'".to_string())
+        );
+        assert_eq!(uint::<u32>().parse_all("12\r\n"), Ok(12));
+    }
+
+    #[test]
+    fn str_ext() {
+        use crate::StrExt;
+
+        assert_eq!(
+            "1,2\n".parse_with(separated(uint::<u32>(), ',')),
+            vec![1, 2]
+        );
+        assert_eq!(String::from("-3").parse_with(int::<i64>()), -3);
+    }
+}
@@ -0,0 +1,307 @@
+use regex::Regex;
+
+use crate::diagnostic::Diagnostic;
+
+pub const DIGITS: &str = "0123456789";
+pub const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+
+// What an input is expected to look like, checked before parsing so that a wrong
+// input is reported with its position instead of panicking somewhere in the parser.
+//
+// ```ignore
+// let warehouse = Shape::grid("#.O@");
+// let moves = Shape::chars("<>^v");
+//
+// Shape::sections([warehouse, moves]).check(input);
+// ```
+#[derive(Debug, Clone)]
+pub enum Shape {
+    Any,
+    // Lines made of the given chars, all equally long if `rectangular`
+    Grid { chars: String, rectangular: bool },
+    // At least one line, each matching the regex
+    Lines(Regex),
+    // Blank line separated sections, one per shape
+    Sections(Vec<Shape>),
+}
+
+impl Shape {
+    pub fn grid(chars: impl Into<String>) -> Self {
+        Shape::Grid {
+            chars: chars.into(),
+            rectangular: true,
+        }
+    }
+
+    // Like a grid, but lines may differ in length
+    pub fn chars(chars: impl Into<String>) -> Self {
+        Shape::Grid {
+            chars: chars.into(),
+            rectangular: false,
+        }
+    }
+
+    // Regexes are matched against whole lines, so they don't need to be anchored
+    pub fn lines(regex: &str) -> Self {
+        Shape::Lines(Regex::new(&format!("^(?:{regex})$")).unwrap())
+    }
+
+    // Lines of (possibly negative) integers separated by `separator`
+    pub fn numbers(separator: &str) -> Self {
+        let separator = regex::escape(separator);
+
+        Self::lines(&format!(r"-?\d+(?:{separator}-?\d+)*"))
+    }
+
+    pub fn sections(shapes: impl IntoIterator<Item = Shape>) -> Self {
+        Shape::Sections(shapes.into_iter().collect())
+    }
+
+    pub fn validate(&self, input: &str) -> Result<(), Diagnostic> {
+        match self.mismatch(input, 0) {
+            Some(mismatch) => Err(Diagnostic::new(
+                input,
+                mismatch.offset,
+                mismatch.len,
+                mismatch.message,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // Panics with the diagnostic, for use at the start of a solution
+    pub fn check(&self, input: &str) {
+        if let Err(err) = self.validate(input) {
+            panic!("input has an unexpected shape\n{err}");
+        }
+    }
+
+    // `base` is the offset of `text` within the whole input
+    fn mismatch(&self, text: &str, base: usize) -> Option<Mismatch> {
+        match self {
+            Shape::Any => None,
+            Shape::Grid { chars, rectangular } => {
+                let mut width = None;
+
+                for (offset, line) in lines(text, base) {
+                    if let Some((index, ch)) =
+                        line.char_indices().find(|&(_, ch)| !chars.contains(ch))
+                    {
+                        return Some(Mismatch::new(
+                            offset + index,
+                            ch.len_utf8(),
+                            format!("unexpected {ch:?}, expected one of {chars:?}"),
+                        ));
+                    }
+
+                    let len = line.chars().count();
+                    let expected = *width.get_or_insert(len);
+
+                    if *rectangular && len != expected {
+                        let message = format!("row is {len} wide, expected {expected}");
+
+                        return Some(match line.char_indices().nth(expected) {
+                            Some((index, _)) => {
+                                Mismatch::new(offset + index, line.len() - index, message)
+                            }
+                            None => Mismatch::new(offset + line.len(), 1, message),
+                        });
+                    }
+                }
+
+                match width {
+                    None | Some(0) => Some(Mismatch::new(base, 1, "expected a grid".into())),
+                    Some(_) => None,
+                }
+            }
+            Shape::Lines(regex) => {
+                let mut lines = lines(text, base).peekable();
+
+                if lines.peek().is_none() {
+                    return Some(Mismatch::new(base, 1, "expected at least one line".into()));
+                }
+
+                lines
+                    .find(|(_, line)| !regex.is_match(line))
+                    .map(|(offset, line)| {
+                        let pattern = regex.as_str();
+                        let pattern = &pattern["^(?:".len()..pattern.len() - ")$".len()];
+
+                        Mismatch::new(
+                            offset,
+                            line.len(),
+                            format!("line does not match /{pattern}/"),
+                        )
+                    })
+            }
+            Shape::Sections(shapes) => {
+                let sections = sections(text, base);
+
+                for (found, &(offset, section)) in sections.iter().enumerate() {
+                    let Some(shape) = shapes.get(found) else {
+                        let len = section.lines().next().map_or(0, str::len);
+
+                        return Some(Mismatch::new(
+                            offset,
+                            len,
+                            format!("unexpected section, expected {} sections", shapes.len()),
+                        ));
+                    };
+
+                    if let Some(mismatch) = shape.mismatch(section, offset) {
+                        return Some(mismatch);
+                    }
+                }
+
+                (sections.len() < shapes.len()).then(|| {
+                    Mismatch::new(
+                        base + text.trim_end_matches(['\n', '\r']).len(),
+                        1,
+                        format!(
+                            "expected {} sections, found {}",
+                            shapes.len(),
+                            sections.len()
+                        ),
+                    )
+                })
+            }
+        }
+    }
+}
+
+// Lines with the offsets they start at, without line terminators
+fn lines(text: &str, base: usize) -> impl Iterator<Item = (usize, &str)> + '_ {
+    text.split_inclusive('\n').scan(base, |offset, line| {
+        let start = *offset;
+
+        *offset += line.len();
+
+        Some((start, line.trim_end_matches(['\n', '\r'])))
+    })
+}
+
+// Blank line separated sections with the offsets they start at, for `\n` and `\r\n` alike
+fn sections(text: &str, base: usize) -> Vec<(usize, &str)> {
+    let body = text.trim_end_matches(['\n', '\r']);
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut end = 0;
+
+    for (offset, line) in lines(body, 0) {
+        if line.is_empty() {
+            sections.push((base + start, &body[start..end]));
+            start = offset + body[offset..].find('\n').map_or(0, |index| index + 1);
+            end = start;
+        } else {
+            end = offset + line.len();
+        }
+    }
+
+    sections.push((base + start, &body[start..end]));
+
+    sections
+}
+
+struct Mismatch {
+    offset: usize,
+    len: usize,
+    message: String,
+}
+
+impl Mismatch {
+    fn new(offset: usize, len: usize, message: String) -> Self {
+        Self {
+            offset,
+            len,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(shape: &Shape, input: &str) -> (usize, usize, String) {
+        let err = shape.validate(input).unwrap_err();
+
+        (err.line, err.column, err.message)
+    }
+
+    #[test]
+    fn accepts_matching_inputs() {
+        assert!(Shape::grid("#.").validate("#.#\n.#.\n").is_ok());
+        assert!(Shape::chars("<>^v").validate("<>\n^v^v").is_ok());
+        assert!(Shape::numbers(",").validate("1,-2,3\n4\n").is_ok());
+        assert!(Shape::Any.validate("").is_ok());
+    }
+
+    #[test]
+    fn reports_a_bad_line_in_the_middle() {
+        let shape = Shape::numbers(" ");
+        let input = "1 2 3\n4 x 6\n7 8 9\n";
+
+        assert_eq!(
+            shape.validate(input).unwrap_err().to_string(),
+            "error: line does not match /-?\\d+(?: -?\\d+)*/\n --> line 2, column 1\n  |\n2 | 4 x 6\n  | ^^^^^"
+        );
+    }
+
+    #[test]
+    fn reports_grid_chars_and_widths() {
+        let shape = Shape::grid("#.");
+
+        assert_eq!(
+            position(&shape, "#..\n#x.\n#..\n"),
+            (2, 2, "unexpected 'x', expected one of \"#.\"".into())
+        );
+        assert_eq!(
+            shape.validate("#..\n#....\n#..\n").unwrap_err().to_string(),
+            "error: row is 5 wide, expected 3\n --> line 2, column 4\n  |\n2 | #....\n  |    ^^"
+        );
+        assert_eq!(
+            position(&shape, "#..\n#.\n"),
+            (2, 3, "row is 2 wide, expected 3".into())
+        );
+        assert_eq!(position(&shape, ""), (1, 1, "expected a grid".into()));
+        assert!(Shape::chars("#.").validate("#..\n#.\n").is_ok());
+    }
+
+    #[test]
+    fn reports_positions_within_sections() {
+        let shape = Shape::sections([Shape::grid("#.O@"), Shape::chars("<>^v")]);
+
+        assert!(shape.validate("#@#\n#O#\n\n<>\nv^\n").is_ok());
+        assert_eq!(
+            position(&shape, "#@#\n#O#\n\n<>\nvx\n"),
+            (5, 2, "unexpected 'x', expected one of \"<>^v\"".into())
+        );
+        assert_eq!(
+            position(&shape, "#@#\n#O#\n"),
+            (2, 4, "expected 2 sections, found 1".into())
+        );
+        assert_eq!(
+            position(&shape, "#@#\n\n<>\n\n12\n"),
+            (5, 1, "unexpected section, expected 2 sections".into())
+        );
+    }
+
+    #[test]
+    fn treats_crlf_blank_lines_as_section_breaks() {
+        let shape = Shape::sections([Shape::grid("#.O@"), Shape::chars("<>^v")]);
+
+        assert!(shape.validate("#@#\r\n#O#\r\n\r\n<>\r\nv^\r\n").is_ok());
+        assert_eq!(
+            shape
+                .validate("#@#\r\n#O#\r\n\r\n<>\r\nvx\r\n")
+                .unwrap_err()
+                .to_string(),
+            "error: unexpected 'x', expected one of \"<>^v\"\n --> line 5, column 2\n  |\n5 | vx\n  |  ^"
+        );
+        assert_eq!(
+            position(&shape, "#@#\r\n#O#\r\n"),
+            (2, 4, "expected 2 sections, found 1".into())
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use crate::shape::Shape;
+
+// What a part evaluates to. Integers that don't fit into an `i64` (e.g. large `u64`s)
+// become `BigInt`, so that equal numbers always compare equal regardless of their type.
+// `u128`s beyond even that become `Str`. Parts that aren't solved yet are `Unsolved`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn as_int(&self) -> Option<i128> {
+        match *self {
+            Answer::Int(n) => Some(n.into()),
+            Answer::BigInt(n) => Some(n),
+            Answer::Str(_) | Answer::Unsolved => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                i64::try_from(n)
+                    .map(Answer::Int)
+                    .or_else(|_| i128::try_from(n).map(Answer::BigInt))
+                    .unwrap_or_else(|_| Answer::Str(n.to_string()))
+            }
+        }
+    )*};
+}
+
+impl_from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+// Both parts of a day, sharing one parse. `Input` may borrow from the raw input.
+//
+// ```ignore
+// pub struct Day1;
+//
+// impl Solution for Day1 {
+//     type Input<'a> = LocationLists;
+//
+//     fn shape() -> Shape {
+//         Shape::lines(r"\d+ +\d+")
+//     }
+//
+//     fn parse(input: &str) -> LocationLists {
+//         LocationLists::parse(input)
+//     }
+//
+//     fn part1(lists: &LocationLists) -> Answer {
+//         lists.total_distance().into()
+//     }
+//
+//     fn part2(lists: &LocationLists) -> Answer {
+//         lists.similarity_score().into()
+//     }
+// }
+// ```
+pub trait Solution {
+    type Input<'a>;
+
+    // Checked before parsing, so that wrong inputs are reported with a position
+    fn shape() -> Shape {
+        Shape::Any
+    }
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    // Parts that aren't written yet are simply left out of the impl
+    fn part1(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn solve(input: &str, part: u32) -> Answer {
+        Self::shape().check(input);
+
+        let input = Self::parse(input);
+
+        match part {
+            1 => Self::part1(&input),
+            2 => Self::part2(&input),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+
+    fn solve_both(input: &str) -> (Answer, Answer) {
+        Self::shape().check(input);
+
+        let input = Self::parse(input);
+
+        (Self::part1(&input), Self::part2(&input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        type Input<'a> = &'a str;
+
+        fn parse(input: &str) -> &str {
+            input
+        }
+
+        fn part1(input: &&str) -> Answer {
+            input.len().into()
+        }
+    }
+
+    #[test]
+    fn integers_compare_equal_regardless_of_type() {
+        assert_eq!(Answer::from(7u8), Answer::from(7i64));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX.into()));
+        assert_eq!(Answer::from(u64::MAX), Answer::from(u128::from(u64::MAX)));
+        assert_eq!(Answer::from(i128::MIN), Answer::BigInt(i128::MIN));
+        assert_eq!(Answer::from(u128::MAX), Answer::Str(u128::MAX.to_string()));
+        assert_eq!(Answer::from(u128::MAX).as_int(), None);
+    }
+
+    #[test]
+    fn parts_left_out_are_unsolved() {
+        assert_eq!(Unfinished::solve("abc", 1), Answer::Int(3));
+        assert_eq!(Unfinished::solve("abc", 2), Answer::Unsolved);
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
@@ -0,0 +1,56 @@
+mod frame;
+mod player;
+mod record;
+
+pub use frame::{Cell, Color, Frame, Palette};
+pub use player::Player;
+pub use record::{load_log, Recorder};
+
+// Where solutions send their frames. Frames are built lazily, so with `Disabled`
+// the closures passed to `emit` are never called and everything compiles away.
+//
+// ```ignore
+// fn simulate(&mut self, viz: &mut impl Visualizer) {
+//     for step in 0.. {
+//         viz.emit(|| self.frame().with_title(format!("step {step}")));
+//         ...
+//     }
+// }
+// ```
+pub trait Visualizer {
+    // Called once per frame before it is built; `false` skips the frame
+    fn accept(&mut self) -> bool {
+        true
+    }
+
+    fn show(&mut self, frame: Frame);
+
+    fn emit(&mut self, frame: impl FnOnce() -> Frame)
+    where
+        Self: Sized,
+    {
+        if self.accept() {
+            self.show(frame());
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Disabled;
+
+impl Visualizer for Disabled {
+    #[inline(always)]
+    fn accept(&mut self) -> bool {
+        false
+    }
+
+    #[inline(always)]
+    fn show(&mut self, _frame: Frame) {}
+}
+
+// Shows all frames once, e.g. ones read back with `load_log`
+pub fn replay(frames: impl IntoIterator<Item = Frame>, viz: &mut impl Visualizer) {
+    for frame in frames {
+        viz.emit(|| frame);
+    }
+}
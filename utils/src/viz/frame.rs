@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    // Roughly xterm's defaults, for exporting frames as images
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::Gray => (127, 127, 127),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    fn sgr(&self, background: bool) -> String {
+        let offset = if background { 10 } else { 0 };
+
+        let code = match *self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::Gray => 90,
+            Color::Rgb(r, g, b) => return format!("{};2;{r};{g};{b}", 38 + offset),
+        };
+
+        (code + offset).to_string()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+}
+
+impl Cell {
+    pub fn new(ch: char) -> Self {
+        Self {
+            ch,
+            fg: None,
+            bg: None,
+        }
+    }
+
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    fn style(&self) -> (Option<Color>, Option<Color>) {
+        (self.fg, self.bg)
+    }
+}
+
+impl From<char> for Cell {
+    fn from(ch: char) -> Self {
+        Self::new(ch)
+    }
+}
+
+// Colours per character, so that a plain text grid can be rendered in colour
+#[derive(Debug, Clone, Default)]
+pub struct Palette {
+    styles: HashMap<char, (Option<Color>, Option<Color>)>,
+}
+
+impl Palette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fg(mut self, ch: char, color: Color) -> Self {
+        self.styles.entry(ch).or_default().0 = Some(color);
+        self
+    }
+
+    pub fn bg(mut self, ch: char, color: Color) -> Self {
+        self.styles.entry(ch).or_default().1 = Some(color);
+        self
+    }
+
+    pub fn cell(&self, ch: char) -> Cell {
+        let (fg, bg) = self.styles.get(&ch).copied().unwrap_or_default();
+
+        Cell { ch, fg, bg }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    title: Option<String>,
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, Cell::new(' '))
+    }
+
+    pub fn filled(width: usize, height: usize, cell: Cell) -> Self {
+        Self {
+            title: None,
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> Cell) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+
+        Self {
+            title: None,
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Short lines are padded with spaces
+    pub fn from_text(text: &str, palette: &Palette) -> Self {
+        let rows = text.lines().collect::<Vec<_>>();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        Self::from_fn(width, rows.len(), |x, y| {
+            palette.cell(rows[y].chars().nth(x).unwrap_or(' '))
+        })
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get<C: TryInto<usize>>(&self, pos: (C, C)) -> Option<&Cell> {
+        self.index(pos).map(|index| &self.cells[index])
+    }
+
+    // Cells outside the frame are ignored, which makes drawing clipped shapes easy
+    pub fn set<C: TryInto<usize>>(&mut self, pos: (C, C), cell: impl Into<Cell>) {
+        if let Some(index) = self.index(pos) {
+            self.cells[index] = cell.into();
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> + '_ {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    pub fn plain(&self) -> String {
+        self.rows()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.ch)
+                    .chain(['\n'])
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    // Escape codes are only emitted where the style changes and reset at each line end
+    pub fn ansi(&self) -> String {
+        let mut out = String::new();
+
+        for row in self.rows() {
+            let mut style = (None, None);
+
+            for cell in row {
+                if cell.style() != style {
+                    style = cell.style();
+                    out.push_str("\x1b[0");
+
+                    if let Some(fg) = style.0 {
+                        write!(out, ";{}", fg.sgr(false)).unwrap();
+                    }
+
+                    if let Some(bg) = style.1 {
+                        write!(out, ";{}", bg.sgr(true)).unwrap();
+                    }
+
+                    out.push('m');
+                }
+
+                out.push(cell.ch);
+            }
+
+            if style != (None, None) {
+                out.push_str("\x1b[0m");
+            }
+
+            out.push('\n');
+        }
+
+        out
+    }
+
+    fn index<C: TryInto<usize>>(&self, (x, y): (C, C)) -> Option<usize> {
+        let x = x.try_into().ok().filter(|&x| x < self.width)?;
+        let y = y.try_into().ok().filter(|&y| y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.plain())
+    }
+}
@@ -0,0 +1,175 @@
+use std::io::{self, BufRead, Write};
+use std::thread;
+use std::time::Duration;
+
+use super::{Frame, Visualizer};
+
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+// Plays frames in the terminal. When stepping, it waits after each frame for
+//
+//   <enter>  the next frame
+//   <n>      skipping the next n frames
+//   c        continuing without stepping
+//   q        quitting, no more frames are built or shown
+pub struct Player {
+    delay: Duration,
+    stepping: bool,
+    every: usize,
+    skip: usize,
+    color: bool,
+    clear: bool,
+    frames: usize,
+    // Index of the frame let through by `accept`, if `show` hasn't been called for it yet
+    accepted: Option<usize>,
+    quit: bool,
+}
+
+impl Player {
+    pub fn new() -> Self {
+        Self {
+            delay: Duration::from_millis(100),
+            stepping: false,
+            every: 1,
+            skip: 0,
+            color: true,
+            clear: true,
+            frames: 0,
+            accepted: None,
+            quit: false,
+        }
+    }
+
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn stepping(mut self, stepping: bool) -> Self {
+        self.stepping = stepping;
+        self
+    }
+
+    // Only shows every nth frame, counting from the first one not skipped
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    pub fn skip(mut self, frames: usize) -> Self {
+        self.skip = frames;
+        self
+    }
+
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    // Without clearing, frames are printed below each other, which is handy when piping
+    pub fn clear(mut self, clear: bool) -> Self {
+        self.clear = clear;
+        self
+    }
+
+    fn prompt(&mut self) {
+        print!("[enter] next, <n> skip n, c continue, q quit: ");
+        io::stdout().flush().unwrap();
+
+        let mut line = String::new();
+
+        if io::stdin().lock().read_line(&mut line).unwrap() == 0 {
+            self.quit = true;
+            return;
+        }
+
+        match line.trim() {
+            "" => {}
+            "c" => self.stepping = false,
+            "q" => self.quit = true,
+            n => match n.parse::<usize>() {
+                Ok(n) => self.skip = self.frames + n,
+                Err(_) => self.prompt(),
+            },
+        }
+    }
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Visualizer for Player {
+    fn accept(&mut self) -> bool {
+        let index = self.frames;
+
+        self.frames += 1;
+
+        let accepted =
+            !self.quit && index >= self.skip && (index - self.skip).is_multiple_of(self.every);
+
+        self.accepted = accepted.then_some(index);
+        accepted
+    }
+
+    fn show(&mut self, frame: Frame) {
+        // Frames shown without going through `accept` are counted here instead
+        let index = self.accepted.take().unwrap_or_else(|| {
+            self.frames += 1;
+            self.frames - 1
+        });
+        let grid = if self.color {
+            frame.ansi()
+        } else {
+            frame.plain()
+        };
+        let mut out = io::stdout().lock();
+
+        if self.clear {
+            out.write_all(CLEAR.as_bytes()).unwrap();
+        }
+
+        match frame.title() {
+            Some(title) => writeln!(out, "frame {index}: {title}").unwrap(),
+            None => writeln!(out, "frame {index}").unwrap(),
+        }
+
+        out.write_all(grid.as_bytes()).unwrap();
+        out.flush().unwrap();
+        drop(out);
+
+        if self.stepping {
+            self.prompt();
+        } else {
+            thread::sleep(self.delay);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shows_frames_without_accepting_them() {
+        let mut player = Player::new()
+            .delay(Duration::ZERO)
+            .color(false)
+            .clear(false);
+
+        player.show(Frame::new(2, 1));
+        player.show(Frame::new(2, 1));
+
+        assert_eq!(player.frames, 2);
+    }
+
+    #[test]
+    fn accepts_every_nth_frame_after_skipping() {
+        let mut player = Player::new().skip(2).every(3);
+        let accepted = (0..10).filter(|_| player.accept()).collect::<Vec<_>>();
+
+        assert_eq!(accepted, [2, 5, 8]);
+    }
+}
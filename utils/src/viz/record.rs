@@ -0,0 +1,194 @@
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use super::{Frame, Palette, Visualizer};
+
+const FRAME_HEADER: &str = "--- frame ";
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Format {
+    // Every frame as plain text below a `--- frame <n>[: <title>]` line
+    Text,
+    // asciinema's v2 format, one output event per frame, `delay` apart
+    Asciicast { delay: Duration },
+}
+
+pub struct Recorder<W: Write> {
+    out: W,
+    format: Format,
+    frames: usize,
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn text(out: W) -> Self {
+        Self {
+            out,
+            format: Format::Text,
+            frames: 0,
+        }
+    }
+
+    pub fn asciicast(out: W, delay: Duration) -> Self {
+        Self {
+            out,
+            format: Format::Asciicast { delay },
+            frames: 0,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    fn write_frame(&mut self, frame: &Frame) -> io::Result<()> {
+        let index = self.frames;
+
+        match self.format {
+            Format::Text => {
+                write!(self.out, "{FRAME_HEADER}{index}")?;
+
+                if let Some(title) = frame.title() {
+                    write!(self.out, ": {title}")?;
+                }
+
+                writeln!(self.out)?;
+                self.out.write_all(frame.plain().as_bytes())?;
+            }
+            Format::Asciicast { delay } => {
+                if index == 0 {
+                    writeln!(
+                        self.out,
+                        r#"{{"version": 2, "width": {}, "height": {}}}"#,
+                        frame.width().max(1),
+                        frame.height() + 1
+                    )?;
+                }
+
+                let mut screen = String::from("\x1b[H\x1b[2J");
+
+                screen.push_str(frame.title().unwrap_or_default());
+                screen.push('\n');
+                screen.push_str(&frame.ansi());
+
+                let time = delay.as_secs_f64() * index as f64;
+                let screen = screen.replace('\n', "\r\n");
+
+                writeln!(self.out, "[{time:.3}, \"o\", {}]", json_string(&screen))?;
+            }
+        }
+
+        self.frames += 1;
+
+        self.out.flush()
+    }
+}
+
+impl Recorder<BufWriter<File>> {
+    // Records an asciicast if the file name ends in `.cast` and a text log otherwise
+    pub fn create(path: impl AsRef<Path>, delay: Duration) -> io::Result<Self> {
+        let path = path.as_ref();
+        let out = BufWriter::new(File::create(path)?);
+
+        Ok(match path.extension() {
+            Some(extension) if extension == "cast" => Self::asciicast(out, delay),
+            _ => Self::text(out),
+        })
+    }
+}
+
+impl<W: Write> Visualizer for Recorder<W> {
+    fn show(&mut self, frame: Frame) {
+        self.write_frame(&frame).expect("failed to record frame");
+    }
+}
+
+// Reads back a text log written by a `Recorder`, applying `palette` to its characters
+pub fn load_log(path: impl AsRef<Path>, palette: &Palette) -> io::Result<Vec<Frame>> {
+    let log = fs::read_to_string(path)?;
+    let mut frames = Vec::new();
+    let mut title = None;
+    let mut grid = String::new();
+    let mut finish = |title: Option<Option<&str>>, grid: &mut String| {
+        if let Some(title) = title {
+            let frame = Frame::from_text(grid, palette);
+
+            frames.push(match title {
+                Some(title) => frame.with_title(title),
+                None => frame,
+            });
+        }
+
+        grid.clear();
+    };
+
+    for line in log.lines() {
+        match line.strip_prefix(FRAME_HEADER) {
+            Some(header) => {
+                finish(title, &mut grid);
+                title = Some(header.split_once(": ").map(|(_, title)| title));
+            }
+            None => writeln!(grid, "{line}").unwrap(),
+        }
+    }
+
+    finish(title, &mut grid);
+
+    Ok(frames)
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch.is_control() => write!(json, "\\u{:04x}", ch as u32).unwrap(),
+            ch => json.push(ch),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::viz::Color;
+    use std::env;
+
+    #[test]
+    fn text_logs_load_back_as_the_recorded_frames() {
+        let path = env::temp_dir().join(format!("utils-viz-{}.log", std::process::id()));
+        let palette = Palette::new().fg('#', Color::Red).bg('O', Color::Blue);
+        let frames = vec![
+            Frame::from_text("#.O\n.#.\n", &palette).with_title("step 0: start"),
+            Frame::from_text("#..\nO#.\n", &palette),
+            Frame::from_text("...\n...\n", &palette).with_title("done"),
+        ];
+        let mut recorder = Recorder::create(&path, Duration::ZERO).unwrap();
+
+        for frame in &frames {
+            recorder.emit(|| frame.clone());
+        }
+
+        drop(recorder);
+
+        let loaded = load_log(&path, &palette);
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), frames);
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("a\"b\\c\r\n\x1b"), r#""a\"b\\c\r\n\u001b""#);
+    }
+}